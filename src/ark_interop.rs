@@ -0,0 +1,72 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Conversions between [`BlsScalar`] and the scalar field of the arkworks
+//! BLS12-381 implementation.
+//!
+//! Both libraries keep scalars in Montgomery form, each with its own
+//! internal layout, so the conversions go through the canonical
+//! little-endian limbs of the scalar.
+
+use ark_bls12_381::Fr;
+use ark_ff::{BigInteger256, PrimeField};
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+
+/// Converts a [`BlsScalar`] into the same element of the arkworks
+/// [`Fr`] field.
+pub fn scalar_to_ark(scalar: BlsScalar) -> Fr {
+    let mut limbs = [0u64; 4];
+    let mut limb = [0u8; 8];
+    limbs
+        .iter_mut()
+        .zip(scalar.to_bytes().chunks_exact(8))
+        .for_each(|(l, chunk)| {
+            limb.copy_from_slice(chunk);
+            *l = u64::from_le_bytes(limb);
+        });
+
+    // Both fields share the same modulus, so a reduced scalar is always a
+    // valid representation
+    Fr::from_repr(BigInteger256(limbs)).unwrap()
+}
+
+/// Converts an element of the arkworks [`Fr`] field into the same
+/// [`BlsScalar`].
+pub fn scalar_from_ark(scalar: Fr) -> BlsScalar {
+    BlsScalar::from_raw(scalar.into_repr().0)
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_scalar_ark_round_trip() {
+        for _ in 0..100 {
+            let a = BlsScalar::random(&mut OsRng);
+            let b = BlsScalar::random(&mut OsRng);
+            assert_eq!(scalar_from_ark(scalar_to_ark(a)), a);
+
+            // The conversion preserves the field operations
+            assert_eq!(
+                scalar_to_ark(a * b),
+                scalar_to_ark(a) * scalar_to_ark(b)
+            );
+            assert_eq!(
+                scalar_to_ark(a + b),
+                scalar_to_ark(a) + scalar_to_ark(b)
+            );
+        }
+
+        assert_eq!(scalar_to_ark(BlsScalar::zero()), Fr::from(0u64));
+        assert_eq!(scalar_to_ark(BlsScalar::one()), Fr::from(1u64));
+        assert_eq!(scalar_to_ark(-BlsScalar::one()), -Fr::from(1u64));
+        assert_eq!(scalar_from_ark(Fr::from(42u64)), BlsScalar::from(42));
+    }
+}
@@ -6,9 +6,10 @@
 
 //! Tools & traits for PLONK circuits
 
-use crate::commitment_scheme::kzg10::PublicParameters;
+use crate::commitment_scheme::kzg10::{OpeningKey, PublicParameters};
 use crate::constraint_system::StandardComposer;
 use crate::error::Error;
+use crate::fft::{EvaluationDomain, Polynomial};
 use crate::proof_system::{Proof, Prover, ProverKey, Verifier, VerifierKey};
 use alloc::vec::Vec;
 #[cfg(feature = "canon")]
@@ -96,13 +97,24 @@ impl VerifierData {
     }
 
     /// Serializes `VerifierData` from a slice of bytes.
+    ///
+    /// The public input positions must be strictly increasing and lie
+    /// within the domain of the [`VerifierKey`], as the ones returned by
+    /// [`StandardComposer::pi_positions`], so that they cannot get out of
+    /// sync with the key.
     pub fn from_slice(mut buf: &[u8]) -> Result<Self, Error> {
         let key = VerifierKey::from_reader(&mut buf)?;
         let pos_num = u32::from_reader(&mut buf)? as usize;
 
         let mut pi_pos = vec![];
         for _ in 0..pos_num {
-            pi_pos.push(u32::from_reader(&mut buf)? as usize);
+            let pos = u32::from_reader(&mut buf)? as usize;
+            if pos >= key.padded_circuit_size()
+                || pi_pos.last().map_or(false, |&last| pos <= last)
+            {
+                return Err(dusk_bytes::Error::InvalidData.into());
+            }
+            pi_pos.push(pos);
         }
 
         Ok(Self { key, pi_pos })
@@ -315,6 +327,74 @@ pub fn verify_proof(
     )
 }
 
+/// Deserializes a [`VerifierKey`] and a [`Proof`] and verifies the proof
+/// against the dense vector of `public_inputs`, as taken by
+/// [`Verifier::verify`].
+///
+/// `vk_bytes` and `proof_bytes` are the outputs of `VerifierKey::to_bytes`
+/// and `Proof::to_bytes`. Failing to read them is reported as
+/// [`Error::VerifierKeyDecode`] and [`Error::ProofDecode`] respectively, so
+/// that they are not mistaken for an invalid proof, which is reported as
+/// [`Error::ProofVerificationError`]. Any other error, such as
+/// [`Error::InvalidEvalDomainSize`] for a key whose circuit size has no
+/// evaluation domain, is returned unchanged.
+pub fn verify_from_bytes(
+    opening_key: &OpeningKey,
+    vk_bytes: &[u8],
+    proof_bytes: &[u8],
+    public_inputs: &[BlsScalar],
+    transcript_init: &'static [u8],
+) -> Result<(), Error> {
+    let verifier_key = VerifierKey::from_slice(vk_bytes)
+        .map_err(|_| Error::VerifierKeyDecode)?;
+    let proof =
+        Proof::from_slice(proof_bytes).map_err(|_| Error::ProofDecode)?;
+
+    let mut verifier = Verifier::new(transcript_init);
+    verifier.verifier_key = Some(verifier_key);
+    verifier
+        .verify(&proof, opening_key, public_inputs)
+        .map_err(|err| match err {
+            Error::PairingCheckFailure => Error::ProofVerificationError,
+            err => err,
+        })
+}
+
+/// Computes the public input polynomial of a circuit over `domain`.
+///
+/// The polynomial evaluates to `-values[i]` at the Lagrange position
+/// `indices[i]` of `domain` and to zero everywhere else, the same way the
+/// verifier lays out the public inputs of a proof.
+///
+/// Returns [`Error::MismatchedPublicInputs`] when `indices` and `values`
+/// differ in length and [`Error::PublicInputPositionOutOfRange`] when an
+/// index does not fit in `domain`.
+pub fn public_input_poly(
+    domain: &EvaluationDomain,
+    indices: &[usize],
+    values: &[BlsScalar],
+) -> Result<Polynomial, Error> {
+    if indices.len() != values.len() {
+        return Err(Error::MismatchedPublicInputs {
+            positions: indices.len(),
+            values: values.len(),
+        });
+    }
+
+    let mut pi = vec![BlsScalar::zero(); domain.size()];
+    for (&position, value) in indices.iter().zip(values) {
+        if position >= domain.size() {
+            return Err(Error::PublicInputPositionOutOfRange {
+                position,
+                size: domain.size(),
+            });
+        }
+        pi[position] = -value;
+    }
+
+    Ok(Polynomial::from_coefficients_vec(domain.ifft(&pi)))
+}
+
 /// Build PI vector for Proof verifications.
 fn build_pi(
     pub_input_values: &[PublicInputValue],
@@ -404,6 +484,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_public_input_poly() -> Result<(), Error> {
+        let domain = EvaluationDomain::new(16)?;
+        let indices = [0, 3, 15];
+        let values =
+            [BlsScalar::from(7), BlsScalar::from(11), BlsScalar::from(13)];
+
+        let poly = public_input_poly(&domain, &indices, &values)?;
+        let evaluations = domain.fft(&poly);
+        for (i, evaluation) in evaluations.iter().enumerate() {
+            match indices.iter().position(|&index| index == i) {
+                Some(k) => assert_eq!(evaluation, &-values[k]),
+                None => assert_eq!(evaluation, &BlsScalar::zero()),
+            }
+        }
+
+        // Same vector as the one handed to the verifier
+        let pi_values: Vec<PublicInputValue> =
+            values.iter().map(|&v| v.into()).collect();
+        assert_eq!(
+            poly,
+            Polynomial::from_coefficients_vec(domain.ifft(&build_pi(
+                &pi_values,
+                &indices,
+                domain.size()
+            )))
+        );
+
+        assert!(matches!(
+            public_input_poly(&domain, &indices, &values[..2]),
+            Err(Error::MismatchedPublicInputs {
+                positions: 3,
+                values: 2
+            })
+        ));
+        assert!(matches!(
+            public_input_poly(&domain, &[16], &values[..1]),
+            Err(Error::PublicInputPositionOutOfRange {
+                position: 16,
+                size: 16
+            })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verifier_data_pi_pos() {
+        use crate::commitment_scheme::kzg10::Commitment;
+
+        let key =
+            VerifierKey::from_commitments(12, [Commitment::default(); 15])
+                .unwrap();
+        let bytes =
+            |pi_pos: Vec<usize>| VerifierData::new(key, pi_pos).to_var_bytes();
+
+        let vd = VerifierData::from_slice(&bytes(vec![0, 3, 15])).unwrap();
+        assert_eq!(vd.pi_pos(), &vec![0, 3, 15]);
+
+        // Out of the domain, unsorted and repeated positions
+        for pi_pos in [vec![2, 16], vec![3, 2], vec![3, 3]] {
+            assert!(VerifierData::from_slice(&bytes(pi_pos)).is_err());
+        }
+    }
+
     #[test]
     fn test_full() -> Result<(), Error> {
         use rand_core::OsRng;
@@ -491,6 +636,52 @@ mod tests {
             &public_inputs,
             &verif_data.pi_pos(),
             b"Test",
-        )
+        )?;
+
+        // Verify from the serialized key and proof only
+        let vk_bytes = verif_data.key().to_bytes();
+        let proof_bytes = proof.to_bytes();
+        let mut pi = build_pi(
+            &public_inputs,
+            verif_data.pi_pos(),
+            verif_data.key().padded_circuit_size(),
+        );
+        let verify = |vk_bytes: &[u8], proof_bytes: &[u8], pi: &[BlsScalar]| {
+            verify_from_bytes(
+                pp.opening_key(),
+                vk_bytes,
+                proof_bytes,
+                pi,
+                b"Test",
+            )
+        };
+        verify(&vk_bytes, &proof_bytes, &pi)?;
+
+        assert!(matches!(
+            verify(&vk_bytes[1..], &proof_bytes, &pi),
+            Err(Error::VerifierKeyDecode)
+        ));
+        let mut invalid_proof = proof_bytes;
+        invalid_proof[0] ^= 0xff;
+        assert!(matches!(
+            verify(&vk_bytes, &invalid_proof, &pi),
+            Err(Error::ProofDecode)
+        ));
+
+        // A key without an evaluation domain is not an invalid proof
+        let mut huge_vk = vk_bytes;
+        huge_vk[..u64::SIZE].copy_from_slice(&(1u64 << 40).to_le_bytes());
+        assert!(matches!(
+            verify(&huge_vk, &proof_bytes, &pi),
+            Err(Error::InvalidEvalDomainSize { .. })
+        ));
+
+        pi[verif_data.pi_pos()[0]] += BlsScalar::one();
+        assert!(matches!(
+            verify(&vk_bytes, &proof_bytes, &pi),
+            Err(Error::ProofVerificationError)
+        ));
+
+        Ok(())
     }
 }
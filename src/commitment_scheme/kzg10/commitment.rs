@@ -5,13 +5,34 @@
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
 //! Module containing the representation of a Commitment to a Polynomial.
-use dusk_bls12_381::{G1Affine, G1Projective};
+use crate::error::Error;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective};
+use dusk_bytes::BadLength;
 use dusk_bytes::{DeserializableSlice, Serializable};
+use merlin::Transcript;
+#[cfg(feature = "std")]
+use rayon::prelude::*;
+
+/// Modulus of the bls12_381 base field, as little-endian 64-bit limbs.
+const FP_MODULUS: [u64; 6] = [
+    0xb9fe_ffff_ffff_aaab,
+    0x1eab_fffe_b153_ffff,
+    0x6730_d2a0_f6b0_f624,
+    0x6477_4b84_f385_12bf,
+    0x4b1b_a7b6_434b_acd7,
+    0x1a01_11ea_397f_e69a,
+];
+
+/// Number of random subset sums checked by [`Commitment::batch_validate`].
+const BATCH_VALIDATION_ROUNDS: usize = 128;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 /// Holds a commitment to a polynomial in a form of a [`G1Affine`]-bls12_381
 /// point.
-pub(crate) struct Commitment(
+pub struct Commitment(
     /// The commitment is a group element.
     pub(crate) G1Affine,
 );
@@ -47,6 +68,201 @@ impl Commitment {
     fn identity() -> Commitment {
         Commitment(G1Affine::identity())
     }
+
+    /// Returns `true` if the [`Commitment`] is the identity point, which is
+    /// what committing to the zero polynomial yields.
+    pub fn is_identity(&self) -> bool {
+        self.0.is_identity().into()
+    }
+
+    /// Reads a [`Commitment`] from the raw representation of its point, as
+    /// produced by [`G1Affine::to_raw_bytes`], checking that it is a valid
+    /// element of the prime-order subgroup.
+    pub(crate) fn from_raw_slice(bytes: &[u8]) -> Result<Commitment, Error> {
+        let commitment = Self::from_raw_slice_unvalidated(bytes)?;
+        if !commitment.is_valid() {
+            return Err(Error::PointMalformed);
+        }
+
+        Ok(commitment)
+    }
+
+    /// Reads a [`Commitment`] like [`Commitment::from_raw_slice`], checking
+    /// the encoding of the coordinates but not whether the point belongs to
+    /// the prime-order subgroup.
+    pub(crate) fn from_raw_slice_unvalidated(
+        bytes: &[u8],
+    ) -> Result<Commitment, Error> {
+        if bytes.len() < G1Affine::RAW_SIZE {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        // Each coordinate must be a reduced field element
+        let is_reduced = |coordinate: &[u8]| {
+            let mut limb = [0u8; 8];
+            for (chunk, modulus) in
+                coordinate.chunks_exact(8).zip(FP_MODULUS.iter()).rev()
+            {
+                limb.copy_from_slice(chunk);
+                let limb = u64::from_le_bytes(limb);
+                if limb != *modulus {
+                    return limb < *modulus;
+                }
+            }
+            false
+        };
+        let infinity = bytes[G1Affine::RAW_SIZE - 1];
+        if infinity > 1
+            || !is_reduced(&bytes[..48])
+            || !is_reduced(&bytes[48..96])
+        {
+            return Err(Error::PointMalformed);
+        }
+
+        // The coordinates and the infinity flag were checked above, so the
+        // point can be interpreted without risking an invalid field element.
+        let point = unsafe { G1Affine::from_slice_unchecked(bytes) };
+
+        Ok(Commitment(point))
+    }
+
+    /// Returns `true` if the point lies on the curve and in its prime-order
+    /// subgroup.
+    fn is_valid(&self) -> bool {
+        (self.0.is_on_curve() & self.0.is_torsion_free()).into()
+    }
+
+    /// Checks that every one of `points` lies on the BLS12-381 G1 curve and
+    /// in its prime-order subgroup, returning [`Error::PointNotOnCurve`] with
+    /// the position of the first invalid point otherwise.
+    ///
+    /// Curve membership only costs a few field multiplications and is checked
+    /// point by point. Subgroup membership needs a full scalar multiplication
+    /// per point, so it is instead checked on
+    /// `128` random subset sums of the points. Up to `128` points, checking
+    /// each of them is cheaper and is done instead.
+    ///
+    /// # Soundness
+    ///
+    /// A point of the curve outside of the subgroup has a non-zero component
+    /// in the cofactor torsion. Whatever the other points of a subset are,
+    /// adding that point to the sum or leaving it out yields two sums whose
+    /// torsion components differ, so at most one of them is in the subgroup.
+    /// Each round thus misses an invalid point with probability at most
+    /// `1/2`, and all of them together with probability at most `2^-128`.
+    ///
+    /// A single random linear combination would not be enough: the cofactor
+    /// of G1 is divisible by `3`, so an invalid point can vanish from such a
+    /// combination with probability `1/3`.
+    ///
+    /// The subsets are drawn from a transcript of all the points, so the
+    /// check is deterministic and crafting points that pass it is as hard as
+    /// guessing `128` random bits.
+    pub fn batch_validate(points: &[Commitment]) -> Result<(), Error> {
+        let check_each = || match points.iter().position(|p| !p.is_valid()) {
+            Some(index) => Err(Error::PointNotOnCurve { index }),
+            None => Ok(()),
+        };
+
+        if points.len() <= BATCH_VALIDATION_ROUNDS {
+            return check_each();
+        }
+
+        let off_curve =
+            points.iter().position(|p| !bool::from(p.0.is_on_curve()));
+        if let Some(index) = off_curve {
+            return Err(Error::PointNotOnCurve { index });
+        }
+
+        let mut transcript = Transcript::new(b"commitment_batch_validate");
+        points.iter().for_each(|p| {
+            transcript.append_message(b"point", &p.0.to_raw_bytes())
+        });
+
+        for _ in 0..BATCH_VALIDATION_ROUNDS {
+            let mut sum = G1Projective::identity();
+            for chunk in points.chunks(512) {
+                let mut subset = [0u8; 64];
+                transcript.challenge_bytes(b"subset", &mut subset);
+                chunk
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| (subset[i / 8] >> (i % 8)) & 1 == 1)
+                    .for_each(|(_, p)| sum += p.0);
+            }
+
+            // A sum of points of the subgroup stays in the subgroup, so some
+            // point is invalid and checking each of them locates it
+            if !bool::from(G1Affine::from(sum).is_torsion_free()) {
+                return check_each();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decompresses many [`Commitment`]s, as [`Commitment::from_bytes`]
+    /// would one at a time.
+    ///
+    /// With the `std` feature the points are decompressed on the rayon
+    /// thread pool. Each point is recovered with its own square root and
+    /// subgroup check, which dominate the cost and cannot be shared.
+    #[cfg(feature = "alloc")]
+    pub fn batch_from_compressed(
+        bufs: &[[u8; Self::SIZE]],
+    ) -> Result<Vec<Commitment>, Error> {
+        let mut commitments = vec![Commitment::identity(); bufs.len()];
+        Self::decompress_into(bufs, &mut commitments)?;
+
+        Ok(commitments)
+    }
+
+    /// Reads exactly `K` consecutive compressed [`Commitment`]s from the
+    /// start of `buf`, returning them with the bytes left after them.
+    ///
+    /// The length of `buf` is checked before any point is decompressed.
+    pub fn read_array<const K: usize>(
+        buf: &[u8],
+    ) -> Result<([Commitment; K], &[u8]), Error> {
+        let len = K * Self::SIZE;
+        if buf.len() < len {
+            return Err(Error::bad_length(buf.len(), len));
+        }
+        let (bytes, rest) = buf.split_at(len);
+
+        let mut compressed = [[0u8; Self::SIZE]; K];
+        compressed
+            .iter_mut()
+            .zip(bytes.chunks_exact(Self::SIZE))
+            .for_each(|(c, chunk)| c.copy_from_slice(chunk));
+        let mut commitments = [Commitment::identity(); K];
+        Self::decompress_into(&compressed, &mut commitments)?;
+
+        Ok((commitments, rest))
+    }
+
+    /// Decompresses each of `bufs` into the matching element of
+    /// `commitments`.
+    pub(crate) fn decompress_into(
+        bufs: &[[u8; Self::SIZE]],
+        commitments: &mut [Commitment],
+    ) -> Result<(), Error> {
+        let decompress = |(commitment, buf): (&mut Commitment, _)| {
+            *commitment = Commitment::from_bytes(buf)?;
+            Ok(())
+        };
+
+        #[cfg(not(feature = "std"))]
+        let res = commitments.iter_mut().zip(bufs).try_for_each(decompress);
+
+        #[cfg(feature = "std")]
+        let res = commitments
+            .par_iter_mut()
+            .zip(bufs)
+            .try_for_each(decompress);
+
+        res
+    }
 }
 
 impl Default for Commitment {
@@ -55,6 +271,38 @@ impl Default for Commitment {
     }
 }
 
+impl<'a, 'b> Add<&'b Commitment> for &'a Commitment {
+    type Output = Commitment;
+
+    fn add(self, other: &'b Commitment) -> Commitment {
+        Commitment::from(G1Projective::from(self.0) + other.0)
+    }
+}
+
+impl Add<Commitment> for Commitment {
+    type Output = Commitment;
+
+    fn add(self, other: Commitment) -> Commitment {
+        Commitment::from(G1Projective::from(self.0) + other.0)
+    }
+}
+
+impl<'a, 'b> Mul<&'b BlsScalar> for &'a Commitment {
+    type Output = Commitment;
+
+    fn mul(self, scalar: &'b BlsScalar) -> Commitment {
+        Commitment::from(self.0 * scalar)
+    }
+}
+
+impl Mul<BlsScalar> for Commitment {
+    type Output = Commitment;
+
+    fn mul(self, scalar: BlsScalar) -> Commitment {
+        Commitment::from(self.0 * scalar)
+    }
+}
+
 #[cfg(test)]
 mod commitment_tests {
     use super::*;
@@ -67,4 +315,202 @@ mod commitment_tests {
             .expect("Error on the deserialization");
         assert_eq!(commitment, obtained_comm);
     }
+
+    #[test]
+    fn commitment_from_raw_slice() {
+        let commitment = Commitment::from(
+            G1Affine::generator() * dusk_bls12_381::BlsScalar::from(7),
+        );
+        let bytes = commitment.0.to_raw_bytes();
+        assert_eq!(Commitment::from_raw_slice(&bytes).unwrap(), commitment);
+
+        let identity = Commitment::default();
+        assert_eq!(
+            Commitment::from_raw_slice(&identity.0.to_raw_bytes()).unwrap(),
+            identity
+        );
+
+        // Point outside of the curve
+        let mut off_curve = bytes;
+        off_curve[48] ^= 1;
+        assert!(matches!(
+            Commitment::from_raw_slice(&off_curve),
+            Err(Error::PointMalformed)
+        ));
+
+        // Unreduced x coordinate
+        let mut unreduced = bytes;
+        FP_MODULUS
+            .iter()
+            .zip(unreduced.chunks_mut(8))
+            .for_each(|(limb, c)| c.copy_from_slice(&limb.to_le_bytes()));
+        assert!(matches!(
+            Commitment::from_raw_slice(&unreduced),
+            Err(Error::PointMalformed)
+        ));
+
+        // Invalid infinity flag
+        let mut flag = bytes;
+        flag[G1Affine::RAW_SIZE - 1] = 2;
+        assert!(matches!(
+            Commitment::from_raw_slice(&flag),
+            Err(Error::PointMalformed)
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn commitment_batch_from_compressed() {
+        let bufs: Vec<_> = (0..20u64)
+            .map(|i| {
+                Commitment::from(
+                    G1Affine::generator() * dusk_bls12_381::BlsScalar::from(i),
+                )
+                .to_bytes()
+            })
+            .collect();
+
+        let commitments = Commitment::batch_from_compressed(&bufs).unwrap();
+        assert_eq!(commitments.len(), bufs.len());
+        for (commitment, buf) in commitments.iter().zip(&bufs) {
+            assert_eq!(commitment, &Commitment::from_bytes(buf).unwrap());
+        }
+        assert!(Commitment::batch_from_compressed(&[]).unwrap().is_empty());
+
+        let mut invalid = bufs;
+        invalid[7][47] ^= 1;
+        assert!(Commitment::batch_from_compressed(&invalid).is_err());
+    }
+
+    #[test]
+    fn commitment_read_array() {
+        let commitments: [Commitment; 3] = [
+            Commitment(G1Affine::generator()),
+            Commitment::default(),
+            Commitment::from(
+                G1Affine::generator() * dusk_bls12_381::BlsScalar::from(5),
+            ),
+        ];
+        let mut bytes = [0u8; 3 * Commitment::SIZE + 2];
+        commitments
+            .iter()
+            .zip(bytes.chunks_mut(Commitment::SIZE))
+            .for_each(|(c, chunk)| chunk.copy_from_slice(&c.to_bytes()));
+
+        let (got, rest) = Commitment::read_array::<3>(&bytes).unwrap();
+        assert_eq!(got, commitments);
+        assert_eq!(rest.len(), 2);
+
+        // Same as reading them one at a time
+        let mut buffer = &bytes[..];
+        for c in &got {
+            assert_eq!(c, &Commitment::from_reader(&mut buffer).unwrap());
+        }
+
+        assert!(matches!(
+            Commitment::read_array::<4>(&bytes),
+            Err(Error::BytesError(dusk_bytes::Error::BadLength {
+                found: 146,
+                expected: 192
+            }))
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn commitment_batch_validate() {
+        let mut points: Vec<_> = (0..300u64)
+            .map(|i| {
+                Commitment::from(
+                    G1Affine::generator() * dusk_bls12_381::BlsScalar::from(i),
+                )
+            })
+            .collect();
+        assert!(Commitment::batch_validate(&points).is_ok());
+        assert!(Commitment::batch_validate(&points[..10]).is_ok());
+        assert!(Commitment::batch_validate(&[]).is_ok());
+
+        // The point (0, 2) lies on the curve and has order 3. Its raw bytes
+        // hold y in Montgomery form, i.e. twice the y of the identity.
+        let mut raw = G1Affine::identity().to_raw_bytes();
+        let mut carry = 0;
+        let mut y = [0u64; 6];
+        for (limb, chunk) in y.iter_mut().zip(raw[48..96].chunks(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            let value = u64::from_le_bytes(bytes);
+            *limb = (value << 1) | carry;
+            carry = value >> 63;
+        }
+        let mut borrow = 0;
+        for (limb, modulus) in y.iter_mut().zip(FP_MODULUS.iter()) {
+            let (value, b1) = limb.overflowing_sub(*modulus);
+            let (value, b2) = value.overflowing_sub(borrow);
+            *limb = value;
+            borrow = (b1 | b2) as u64;
+        }
+        raw[48..96]
+            .chunks_mut(8)
+            .zip(y.iter())
+            .for_each(|(chunk, limb)| {
+                chunk.copy_from_slice(&limb.to_le_bytes())
+            });
+        raw[G1Affine::RAW_SIZE - 1] = 0;
+        let torsion = Commitment::from_raw_slice_unvalidated(&raw).unwrap();
+        assert!(bool::from(torsion.0.is_on_curve()));
+
+        for index in [0, 7, 150, 299] {
+            let mut invalid = points.clone();
+            invalid[index] = torsion;
+            assert!(matches!(
+                Commitment::batch_validate(&invalid),
+                Err(Error::PointNotOnCurve { index: i }) if i == index
+            ));
+        }
+        assert!(matches!(
+            Commitment::batch_validate(&[points[1], torsion]),
+            Err(Error::PointNotOnCurve { index: 1 })
+        ));
+
+        // Off the curve
+        let mut raw = points[42].0.to_raw_bytes();
+        raw[48] ^= 1;
+        points[42] = Commitment::from_raw_slice_unvalidated(&raw).unwrap();
+        assert!(matches!(
+            Commitment::batch_validate(&points),
+            Err(Error::PointNotOnCurve { index: 42 })
+        ));
+    }
+
+    #[test]
+    fn commitment_ops() {
+        use dusk_bls12_381::BlsScalar;
+
+        let a = BlsScalar::from(3);
+        let b = BlsScalar::from(11);
+        let c = Commitment(G1Affine::generator());
+
+        assert_eq!(c + Commitment::default(), c);
+        assert_eq!(Commitment::default() + c, c);
+        assert_eq!(c * BlsScalar::zero(), Commitment::default());
+        assert_eq!(c * BlsScalar::one(), c);
+        assert_eq!(c + c, c * BlsScalar::from(2));
+        let (ca, cb) = (c * a, c * b);
+        assert_eq!(ca + cb, c * (a + b));
+
+        // Same results through references
+        let (c_ref, a_ref, cb_ref) = (&c, &a, &cb);
+        assert_eq!(c_ref * a_ref, ca);
+        assert_eq!(&ca + cb_ref, ca + cb);
+        assert_eq!(
+            c * a,
+            Commitment::from(G1Affine::generator() * BlsScalar::from(3))
+        );
+    }
+
+    #[test]
+    fn commitment_is_identity() {
+        assert!(Commitment::default().is_identity());
+        assert!(!Commitment(G1Affine::generator()).is_identity());
+    }
 }
@@ -17,7 +17,13 @@ use dusk_bls12_381::{
     G2Affine, G2Prepared,
 };
 use dusk_bytes::{DeserializableSlice, Serializable};
+use hashbrown::HashMap;
 use merlin::Transcript;
+#[cfg(any(test, feature = "test-setup"))]
+use {
+    super::PublicParameters,
+    rand_core::{CryptoRng, RngCore},
+};
 
 /// CommitKey is used to commit to a polynomial which is bounded by the
 /// max_degree.
@@ -109,7 +115,7 @@ impl CommitKey {
     }
 
     /// Returns the maximum degree polynomial that you can commit to.
-    pub(crate) fn max_degree(&self) -> usize {
+    pub fn max_degree(&self) -> usize {
         self.powers_of_g.len() - 1
     }
 
@@ -148,19 +154,20 @@ impl CommitKey {
     ) -> Result<(), Error> {
         match (poly_degree == 0, poly_degree > self.max_degree()) {
             (true, _) => Err(Error::PolynomialDegreeIsZero),
-            (false, true) => Err(Error::PolynomialDegreeTooLarge),
+            (false, true) => Err(Error::PolynomialDegreeTooLarge {
+                degree: poly_degree,
+                max: self.max_degree(),
+            }),
             (false, false) => Ok(()),
         }
     }
 
     /// Commits to a [`Polynomial`] returning the corresponding [`Commitment`].
     ///
-    /// Returns an error if the polynomial's degree is more than the max degree
-    /// of the commit key.
-    pub(crate) fn commit(
-        &self,
-        polynomial: &Polynomial,
-    ) -> Result<Commitment, Error> {
+    /// Returns [`Error::PolynomialDegreeTooLarge`] if the polynomial's degree
+    /// is more than [`CommitKey::max_degree`], and
+    /// [`Error::PolynomialDegreeIsZero`] for constant polynomials.
+    pub fn commit(&self, polynomial: &Polynomial) -> Result<Commitment, Error> {
         // Check whether we can safely commit to this polynomial
         self.check_commit_degree_is_within_bounds(polynomial.degree())?;
 
@@ -171,6 +178,24 @@ impl CommitKey {
         )))
     }
 
+    /// Commits to a selector [`Polynomial`] like [`CommitKey::commit`], but
+    /// also accepts constant polynomials.
+    ///
+    /// A selector is constant when the circuit enables its gate on none or on
+    /// all of its rows. The empty selector commits to the identity, and any
+    /// other constant `c` to `c` times the first power of the key.
+    pub(crate) fn commit_selector(
+        &self,
+        polynomial: &Polynomial,
+    ) -> Result<Commitment, Error> {
+        if polynomial.degree() != 0 {
+            return self.commit(polynomial);
+        }
+
+        let c = polynomial.coeffs.first().copied().unwrap_or_default();
+        Ok(Commitment::from(self.powers_of_g[0] * c))
+    }
+
     /// Computes a single witness for multiple polynomials at the same point, by
     /// taking a random linear combination of the individual witnesses.
     /// We apply the same optimisation mentioned in when computing each witness;
@@ -182,16 +207,167 @@ impl CommitKey {
         transcript: &mut Transcript,
     ) -> Polynomial {
         let challenge = transcript.challenge_scalar(b"aggregate_witness");
-        let powers = util::powers_of(&challenge, polynomials.len() - 1);
+        let numerator = Self::linear_combination(polynomials, &challenge);
+        numerator.ruffini(*point)
+    }
+
+    /// Opens every polynomial of `polynomials` at `point` with a single
+    /// [`Proof`], by opening their random linear combination with the powers
+    /// of `challenge`.
+    ///
+    /// The proof holds the combined evaluation and the commitment to the
+    /// combined polynomial, which a verifier can rebuild from the individual
+    /// commitments and evaluations with the same powers of `challenge`. The
+    /// challenge must be drawn from a transcript that already holds those
+    /// commitments and evaluations; it is taken as input so the caller
+    /// decides how the transcript is built.
+    ///
+    /// Returns the errors of [`CommitKey::commit`] for the combined and the
+    /// witness polynomials, so [`Error::PolynomialDegreeIsZero`] if no
+    /// polynomial is given.
+    pub fn batch_open(
+        &self,
+        polynomials: &[Polynomial],
+        point: BlsScalar,
+        challenge: BlsScalar,
+    ) -> Result<Proof, Error> {
+        let combined = Self::linear_combination(polynomials, &challenge);
+        let witness = combined.ruffini(point);
+
+        Ok(Proof {
+            commitment_to_witness: self.commit(&witness)?,
+            evaluated_point: combined.evaluate(&point),
+            commitment_to_polynomial: self.commit(&combined)?,
+        })
+    }
+
+    /// Opens `polys_z` at `z` and `polys_zw` at `z * omega` with
+    /// [`CommitKey::batch_open`], combining the first bucket with the powers
+    /// of `challenges.0` and the second with the powers of `challenges.1`.
+    ///
+    /// This is the two-point structure of a PLONK proof, where `omega` is the
+    /// generator of the evaluation domain. The PLONK prover opens, in this
+    /// order:
+    /// - at `z`: the quotient opening polynomial combining the chunks of `t`,
+    ///   the linearisation polynomial, the four wire polynomials `w_l`, `w_r`,
+    ///   `w_o` and `w_4`, and the left, right and out sigma polynomials;
+    /// - at `z * omega`: the permutation accumulator `z` and the wire
+    ///   polynomials `w_l`, `w_r` and `w_4`.
+    ///
+    /// Returns the proofs at `z` and at `z * omega`, or the first error of
+    /// [`CommitKey::batch_open`].
+    pub fn open_multipoint(
+        &self,
+        polys_z: &[Polynomial],
+        polys_zw: &[Polynomial],
+        z: BlsScalar,
+        omega: BlsScalar,
+        challenges: (BlsScalar, BlsScalar),
+    ) -> Result<(Proof, Proof), Error> {
+        Ok((
+            self.batch_open(polys_z, z, challenges.0)?,
+            self.batch_open(polys_zw, z * omega, challenges.1)?,
+        ))
+    }
+
+    /// Computes `sum_i challenge^i * polynomials[i]`, which is the zero
+    /// polynomial if there are none.
+    fn linear_combination(
+        polynomials: &[Polynomial],
+        challenge: &BlsScalar,
+    ) -> Polynomial {
+        if polynomials.is_empty() {
+            return Polynomial::zero();
+        }
+        let powers = util::powers_of(challenge, polynomials.len() - 1);
 
         assert_eq!(powers.len(), polynomials.len());
 
-        let numerator: Polynomial = polynomials
+        polynomials
             .iter()
             .zip(powers.iter())
             .map(|(poly, challenge)| poly * challenge)
-            .sum();
-        numerator.ruffini(*point)
+            .sum()
+    }
+}
+
+/// Caches the [`Commitment`]s to [`Polynomial`]s, so that committing to
+/// the same polynomial again with the same SRS does not recompute the
+/// multi-scalar multiplication.
+///
+/// Entries are keyed by a hash of the polynomial coefficients together with
+/// the first two powers of the [`CommitKey`], so a cache can be shared
+/// between keys: the ones trimmed from the same [`PublicParameters`] share
+/// their entries, while keys from different setups never collide.
+///
+/// [`PublicParameters`]: super::PublicParameters
+#[derive(Debug, Clone, Default)]
+pub struct CommitmentCache {
+    commitments: HashMap<[u8; 32], Commitment>,
+}
+
+impl CommitmentCache {
+    /// Creates an empty [`CommitmentCache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached commitments.
+    pub fn len(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Returns `true` if no commitment is cached.
+    pub fn is_empty(&self) -> bool {
+        self.commitments.is_empty()
+    }
+
+    /// Removes every cached commitment.
+    pub fn clear(&mut self) {
+        self.commitments.clear()
+    }
+
+    /// Commits to `polynomial` with `commit_key`, reusing the cached
+    /// [`Commitment`] if there is one.
+    ///
+    /// Errors are returned exactly as [`CommitKey`] commitments would and are
+    /// never cached.
+    pub(crate) fn commit(
+        &mut self,
+        commit_key: &CommitKey,
+        polynomial: &Polynomial,
+    ) -> Result<Commitment, Error> {
+        let key = Self::key(commit_key, polynomial);
+        if let Some(commitment) = self.commitments.get(&key) {
+            return Ok(*commitment);
+        }
+
+        let commitment = commit_key.commit(polynomial)?;
+        self.commitments.insert(key, commitment);
+        Ok(commitment)
+    }
+
+    /// Hashes the first two powers of `commit_key` and the coefficients of
+    /// `polynomial` up to its degree, so trailing zeros do not matter.
+    fn key(commit_key: &CommitKey, polynomial: &Polynomial) -> [u8; 32] {
+        let mut state = blake2b_simd::Params::new()
+            .hash_length(32)
+            .personal(b"plonk-cmt-cache")
+            .to_state();
+        commit_key.powers_of_g.iter().take(2).for_each(|point| {
+            state.update(&point.to_bytes());
+        });
+        polynomial
+            .coeffs
+            .iter()
+            .take(polynomial.degree() + 1)
+            .for_each(|coeff| {
+                state.update(&coeff.to_bytes());
+            });
+
+        let mut key = [0u8; 32];
+        key.copy_from_slice(state.finalize().as_bytes());
+        key
     }
 }
 
@@ -253,14 +429,52 @@ impl OpeningKey {
         }
     }
 
+    /// Checks that `proof` opens its committed polynomial at `point` to its
+    /// evaluated point, returning [`Error::PairingCheckFailure`] otherwise.
+    ///
+    /// Proofs of [`CommitKey::batch_open`] are checked against the
+    /// commitment and the evaluation that they combine.
+    pub fn check(&self, point: BlsScalar, proof: Proof) -> Result<(), Error> {
+        let inner_a: G1Affine = (proof.commitment_to_polynomial.0
+            - (self.g * proof.evaluated_point))
+            .into();
+
+        let inner_b: G2Affine = (self.beta_h - (self.h * point)).into();
+        let prepared_inner_b = G2Prepared::from(-inner_b);
+
+        let pairing = dusk_bls12_381::multi_miller_loop(&[
+            (&inner_a, &self.prepared_h),
+            (&proof.commitment_to_witness.0, &prepared_inner_b),
+        ])
+        .final_exponentiation();
+
+        if pairing != dusk_bls12_381::Gt::identity() {
+            return Err(Error::PairingCheckFailure);
+        };
+        Ok(())
+    }
+
     /// Checks whether a batch of polynomials evaluated at different points,
     /// returned their specified value.
+    #[cfg(test)]
     pub(crate) fn batch_check(
         &self,
         points: &[BlsScalar],
         proofs: &[Proof],
         transcript: &mut Transcript,
     ) -> Result<(), Error> {
+        let (total_c, total_w) = self.batch_terms(points, proofs, transcript);
+        self.check_terms(total_c, total_w)
+    }
+
+    /// Folds a batch of polynomials evaluated at different points into the
+    /// two points `(c, w)` that [`OpeningKey::check_terms`] expects.
+    pub(crate) fn batch_terms(
+        &self,
+        points: &[BlsScalar],
+        proofs: &[Proof],
+        transcript: &mut Transcript,
+    ) -> (G1Projective, G1Projective) {
         let mut total_c = G1Projective::identity();
         let mut total_w = G1Projective::identity();
 
@@ -283,6 +497,19 @@ impl OpeningKey {
         }
         total_c -= self.g * g_multiplier;
 
+        (total_c, total_w)
+    }
+
+    /// Checks that `e(-w, beta_h) * e(c, h)` is the identity, which holds for
+    /// the folded points of valid openings.
+    ///
+    /// Random linear combinations of the points folded from different
+    /// batches can be checked at once.
+    pub(crate) fn check_terms(
+        &self,
+        total_c: G1Projective,
+        total_w: G1Projective,
+    ) -> Result<(), Error> {
         let affine_total_w = G1Affine::from(-total_w);
         let affine_total_c = G1Affine::from(total_c);
 
@@ -299,34 +526,198 @@ impl OpeningKey {
     }
 }
 
+#[cfg(any(test, feature = "test-setup"))]
+impl CommitKey {
+    /// Deterministically generates a [`CommitKey`] and its [`OpeningKey`]
+    /// for polynomials of degree up to `max_degree`, derived from `seed`.
+    ///
+    /// # Warning
+    ///
+    /// **This setup is insecure and must never be used in production.**
+    /// Anyone knowing `seed` can recompute the secret of the SRS and forge
+    /// proofs. It only exists to make tests and CI runs reproducible and is
+    /// only compiled with the `test-setup` feature.
+    pub fn setup_for_tests(
+        max_degree: usize,
+        seed: u64,
+    ) -> Result<(CommitKey, OpeningKey), Error> {
+        let mut rng = SeededRng::new(seed);
+        PublicParameters::setup(max_degree, &mut rng)?.trim(max_degree)
+    }
+}
+
+/// Deterministic generator expanding a seed with BLAKE2b in counter mode.
+///
+/// It is only marked as [`CryptoRng`] to feed [`PublicParameters::setup`]
+/// and is as predictable as its seed.
+#[cfg(any(test, feature = "test-setup"))]
+struct SeededRng {
+    seed: u64,
+    counter: u64,
+    block: [u8; 64],
+    used: usize,
+}
+
+#[cfg(any(test, feature = "test-setup"))]
+impl SeededRng {
+    fn new(seed: u64) -> Self {
+        SeededRng {
+            seed,
+            counter: 0,
+            block: [0u8; 64],
+            used: 64,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "test-setup"))]
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            if self.used == self.block.len() {
+                let hash = blake2b_simd::Params::new()
+                    .personal(b"plonk-test-srs")
+                    .to_state()
+                    .update(&self.seed.to_le_bytes())
+                    .update(&self.counter.to_le_bytes())
+                    .finalize();
+                self.block.copy_from_slice(hash.as_bytes());
+                self.counter += 1;
+                self.used = 0;
+            }
+            *byte = self.block[self.used];
+            self.used += 1;
+        }
+    }
+
+    fn try_fill_bytes(
+        &mut self,
+        dest: &mut [u8],
+    ) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(any(test, feature = "test-setup"))]
+impl CryptoRng for SeededRng {}
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::commitment_scheme::kzg10::{AggregateProof, PublicParameters};
-    use crate::fft::Polynomial;
+    use crate::fft::{EvaluationDomain, Polynomial};
     use dusk_bls12_381::BlsScalar;
     use dusk_bytes::Serializable;
     use merlin::Transcript;
     use rand_core::OsRng;
 
-    // Checks that a polynomial `p` was evaluated at a point `z` and returned
-    // the value specified `v`. ie. v = p(z).
-    fn check(op_key: &OpeningKey, point: BlsScalar, proof: Proof) -> bool {
-        let inner_a: G1Affine = (proof.commitment_to_polynomial.0
-            - (op_key.g * proof.evaluated_point))
-            .into();
+    #[test]
+    fn test_commit_degree_bounds() {
+        let (commit_key, _) = CommitKey::setup_for_tests(1 << 4, 3).unwrap();
+        let max = commit_key.max_degree();
+        assert_eq!(max, 1 << 4);
+
+        let constant =
+            Polynomial::from_coefficients_vec(vec![BlsScalar::one()]);
+        assert!(matches!(
+            commit_key.commit(&constant),
+            Err(Error::PolynomialDegreeIsZero)
+        ));
+
+        let poly = Polynomial::rand(max, &mut OsRng);
+        let commitment = commit_key.commit(&poly).unwrap();
+        assert_eq!(
+            commitment,
+            Commitment::from(msm_variable_base(
+                &commit_key.powers_of_g,
+                &poly.coeffs
+            ))
+        );
 
-        let inner_b: G2Affine = (op_key.beta_h - (op_key.h * point)).into();
-        let prepared_inner_b = G2Prepared::from(-inner_b);
+        let poly = Polynomial::rand(max + 1, &mut OsRng);
+        assert!(matches!(
+            commit_key.commit(&poly),
+            Err(Error::PolynomialDegreeTooLarge { degree, max: 16 })
+                if degree == max + 1
+        ));
+    }
 
-        let pairing = dusk_bls12_381::multi_miller_loop(&[
-            (&inner_a, &op_key.prepared_h),
-            (&proof.commitment_to_witness.0, &prepared_inner_b),
-        ])
-        .final_exponentiation();
+    #[test]
+    fn test_commitment_cache() {
+        let (commit_key, _) = CommitKey::setup_for_tests(1 << 6, 7).unwrap();
+        let (other_key, _) = CommitKey::setup_for_tests(1 << 6, 8).unwrap();
+        let trimmed_key = commit_key.truncate(1 << 5).unwrap();
+        let poly = Polynomial::rand(1 << 4, &mut OsRng);
+        let fresh = commit_key.commit(&poly).unwrap();
+
+        let mut cache = CommitmentCache::new();
+        assert!(cache.is_empty());
+        assert_eq!(cache.commit(&commit_key, &poly).unwrap(), fresh);
+        assert_eq!(cache.len(), 1);
+
+        // Hits, also through a trimmed key and with trailing zeros
+        let mut padded = poly.clone();
+        padded.coeffs.push(BlsScalar::zero());
+        assert_eq!(cache.commit(&commit_key, &poly).unwrap(), fresh);
+        assert_eq!(cache.commit(&trimmed_key, &padded).unwrap(), fresh);
+        assert_eq!(cache.len(), 1);
+
+        // A different SRS gets an entry of its own
+        assert_eq!(
+            cache.commit(&other_key, &poly).unwrap(),
+            other_key.commit(&poly).unwrap()
+        );
+        assert_eq!(cache.len(), 2);
+
+        // Errors are not cached
+        let large = Polynomial::rand(1 << 6, &mut OsRng);
+        assert!(cache.commit(&trimmed_key, &large).is_err());
+        assert_eq!(cache.len(), 2);
 
-        pairing == dusk_bls12_381::Gt::identity()
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_setup_for_tests() {
+        let (commit_key, opening_key) =
+            CommitKey::setup_for_tests(1 << 6, 42).unwrap();
+        assert_eq!(commit_key.max_degree(), 1 << 6);
+
+        let (same_commit_key, same_opening_key) =
+            CommitKey::setup_for_tests(1 << 6, 42).unwrap();
+        assert_eq!(
+            commit_key.to_raw_var_bytes(),
+            same_commit_key.to_raw_var_bytes()
+        );
+        assert_eq!(opening_key.to_bytes(), same_opening_key.to_bytes());
+
+        let (other_commit_key, _) =
+            CommitKey::setup_for_tests(1 << 6, 43).unwrap();
+        assert_ne!(commit_key, other_commit_key);
+
+        // The keys open commitments like any other setup
+        let poly = Polynomial::rand(1 << 5, &mut OsRng);
+        let point = BlsScalar::random(&mut OsRng);
+        let value = poly.evaluate(&point);
+        let proof = open_single(&commit_key, &poly, &value, &point).unwrap();
+        assert!(check(&opening_key, point, proof));
+    }
+
+    // Checks that a polynomial `p` was evaluated at a point `z` and returned
+    // the value specified `v`. ie. v = p(z).
+    fn check(op_key: &OpeningKey, point: BlsScalar, proof: Proof) -> bool {
+        op_key.check(point, proof).is_ok()
     }
 
     // Creates an opening proof that a polynomial `p` was correctly evaluated at
@@ -477,6 +868,73 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_batch_open() -> Result<(), Error> {
+        let (ck, opening_key) = CommitKey::setup_for_tests(27, 1)?;
+        let point = BlsScalar::from(10);
+        let polys = [
+            Polynomial::rand(25, &mut OsRng),
+            Polynomial::rand(26, &mut OsRng),
+            Polynomial::rand(27, &mut OsRng),
+        ];
+        let evals: Vec<_> = polys.iter().map(|p| p.evaluate(&point)).collect();
+
+        let mut transcript = Transcript::new(b"batch_open");
+        let challenge =
+            transcript.clone().challenge_scalar(b"aggregate_witness");
+        let proof = ck.batch_open(&polys, point, challenge)?;
+        opening_key.check(point, proof)?;
+        assert!(opening_key.check(point + BlsScalar::one(), proof).is_err());
+
+        // Same proof as the aggregated openings of the prover
+        let flattened =
+            open_multiple(&ck, &polys, evals, &point, &mut transcript.clone())?
+                .flatten(&mut transcript);
+        assert_eq!(
+            proof.commitment_to_witness,
+            flattened.commitment_to_witness
+        );
+        assert_eq!(proof.evaluated_point, flattened.evaluated_point);
+        assert_eq!(
+            proof.commitment_to_polynomial,
+            flattened.commitment_to_polynomial
+        );
+
+        assert!(matches!(
+            ck.batch_open(&[], point, challenge),
+            Err(Error::PolynomialDegreeIsZero)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_multipoint() -> Result<(), Error> {
+        let (ck, opening_key) = CommitKey::setup_for_tests(1 << 5, 2)?;
+        let omega = EvaluationDomain::new(1 << 5)?.group_gen;
+        let z = BlsScalar::random(&mut OsRng);
+        let challenges =
+            (BlsScalar::random(&mut OsRng), BlsScalar::random(&mut OsRng));
+        let polys_z = [
+            Polynomial::rand(1 << 5, &mut OsRng),
+            Polynomial::rand(1 << 4, &mut OsRng),
+        ];
+        let polys_zw = [Polynomial::rand(1 << 5, &mut OsRng)];
+
+        let (proof_z, proof_zw) =
+            ck.open_multipoint(&polys_z, &polys_zw, z, omega, challenges)?;
+        opening_key.check(z, proof_z)?;
+        opening_key.check(z * omega, proof_zw)?;
+        assert!(opening_key.check(z, proof_zw).is_err());
+
+        let proof = ck.batch_open(&polys_zw, z * omega, challenges.1)?;
+        assert_eq!(proof_zw.commitment_to_witness, proof.commitment_to_witness);
+        assert_eq!(
+            proof_zw.evaluated_point,
+            polys_zw[0].evaluate(&(z * omega))
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_batch_with_aggregation() -> Result<(), Error> {
         let max_degree = 28;
@@ -11,11 +11,12 @@ if #[cfg(feature = "alloc")]
 {
     pub mod key;
     pub mod srs;
-    pub use key::{CommitKey, OpeningKey};
+    pub use key::{CommitKey, CommitmentCache, OpeningKey};
     pub(crate) use proof::alloc::AggregateProof;
     pub use srs::PublicParameters;
 });
 
 pub(crate) mod commitment;
 pub(crate) mod proof;
-pub(crate) use commitment::Commitment;
+pub use commitment::Commitment;
+pub use proof::Proof;
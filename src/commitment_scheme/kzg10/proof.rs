@@ -10,7 +10,7 @@ use dusk_bls12_381::BlsScalar;
 #[derive(Copy, Clone, Debug)]
 /// Proof that a polynomial `p` was correctly evaluated at a point `z`
 /// producing the evaluated point p(z).
-pub(crate) struct Proof {
+pub struct Proof {
     /// This is a commitment to the witness polynomial.
     pub(crate) commitment_to_witness: Commitment,
     /// This is the result of evaluating a polynomial at the point `z`.
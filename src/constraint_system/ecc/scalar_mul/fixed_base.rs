@@ -11,6 +11,15 @@ use alloc::vec::Vec;
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::Serializable;
 use dusk_jubjub::{JubJubAffine, JubJubExtended, JubJubScalar};
+use hashbrown::HashMap;
+
+/// Number of bits of the WNAF of a [`JubJubScalar`] used by
+/// [`StandardComposer::fixed_base_scalar_mul`].
+// XXX: we can slice off 3 bits from the top of wnaf, since F_r prime
+// has 252 bits. XXX :We can also move to base4 and have half
+// the number of gates since wnaf adjacent entries product is
+// zero, we will not go over the specified amount
+const WNAF_BITS: usize = 256;
 
 fn compute_wnaf_point_multiples(
     generator: JubJubExtended,
@@ -27,6 +36,44 @@ fn compute_wnaf_point_multiples(
     dusk_jubjub::batch_normalize(&mut multiples).collect()
 }
 
+/// Cache of the multiples `2^i G` of fixed base points `G`, so that circuits
+/// multiplying by the same base with
+/// [`StandardComposer::fixed_base_scalar_mul_cached`] compute them once.
+#[derive(Debug, Default, Clone)]
+pub struct FixedBaseWindows {
+    windows: HashMap<[u8; JubJubAffine::SIZE], Vec<JubJubAffine>>,
+}
+
+impl FixedBaseWindows {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of base points in the cache.
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    /// Returns `true` if the cache holds no base point.
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Returns the multiples of `generator` from the highest to the lowest,
+    /// computing them on first use.
+    fn get_or_compute(&mut self, generator: JubJubExtended) -> &[JubJubAffine] {
+        self.windows
+            .entry(JubJubAffine::from(generator).to_bytes())
+            .or_insert_with(|| {
+                let mut point_multiples =
+                    compute_wnaf_point_multiples(generator, WNAF_BITS);
+                point_multiples.reverse();
+                point_multiples
+            })
+    }
+}
+
 impl StandardComposer {
     /// Adds an elliptic curve Scalar multiplication gate to the circuit
     /// description.
@@ -41,17 +88,40 @@ impl StandardComposer {
         jubjub_scalar: Variable,
         generator: JubJubExtended,
     ) -> Point {
-        // XXX: we can slice off 3 bits from the top of wnaf, since F_r prime
-        // has 252 bits. XXX :We can also move to base4 and have half
-        // the number of gates since wnaf adjacent entries product is
-        // zero, we will not go over the specified amount
-        let num_bits = 256;
-
         // compute 2^iG
         let mut point_multiples =
-            compute_wnaf_point_multiples(generator, num_bits);
+            compute_wnaf_point_multiples(generator, WNAF_BITS);
         point_multiples.reverse();
 
+        self.fixed_base_scalar_mul_with(jubjub_scalar, &point_multiples)
+    }
+
+    /// Adds an elliptic curve Scalar multiplication gate to the circuit
+    /// description like [`StandardComposer::fixed_base_scalar_mul`], taking
+    /// the multiples of `generator` from `windows`.
+    ///
+    /// The multiples are computed and stored in `windows` the first time
+    /// `generator` is used, and reused by every circuit sharing the cache.
+    pub fn fixed_base_scalar_mul_cached(
+        &mut self,
+        jubjub_scalar: Variable,
+        generator: JubJubExtended,
+        windows: &mut FixedBaseWindows,
+    ) -> Point {
+        let point_multiples = windows.get_or_compute(generator);
+
+        self.fixed_base_scalar_mul_with(jubjub_scalar, point_multiples)
+    }
+
+    /// Adds the gates of a fixed base scalar multiplication, given the
+    /// multiples `2^iG` of the base from the highest to the lowest.
+    fn fixed_base_scalar_mul_with(
+        &mut self,
+        jubjub_scalar: Variable,
+        point_multiples: &[JubJubAffine],
+    ) -> Point {
+        let num_bits = point_multiples.len();
+
         // Fetch the raw scalar value as bls scalar, then convert to a jubjub
         // scalar XXX: Not very Tidy, impl From function in JubJub
         let raw_bls_scalar = self.variables.get(&jubjub_scalar).unwrap();
@@ -195,6 +265,38 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn test_ecc_constraint_cached() {
+        let mut windows = FixedBaseWindows::new();
+        let scalar = JubJubScalar::from(0xdead_beef_u64);
+        let bls_scalar = BlsScalar::from_bytes(&scalar.to_bytes()).unwrap();
+        let expected_point: JubJubAffine = (GENERATOR_EXTENDED * scalar).into();
+
+        // The cached multiples yield the same circuit as the computed ones
+        let mut uncached = StandardComposer::new();
+        let var = uncached.add_input(bls_scalar);
+        uncached.fixed_base_scalar_mul(var, GENERATOR_EXTENDED);
+
+        for _ in 0..2 {
+            let mut composer = StandardComposer::new();
+            let var = composer.add_input(bls_scalar);
+            let point = composer.fixed_base_scalar_mul_cached(
+                var,
+                GENERATOR_EXTENDED,
+                &mut windows,
+            );
+
+            assert_eq!(composer.variables[&point.x], expected_point.get_x());
+            assert_eq!(composer.variables[&point.y], expected_point.get_y());
+            assert_eq!(composer.circuit_size(), uncached.circuit_size());
+            assert_eq!(composer.q_fixed_group_add, uncached.q_fixed_group_add);
+            assert_eq!(composer.q_l, uncached.q_l);
+            assert_eq!(composer.q_r, uncached.q_r);
+            assert_eq!(composer.q_c, uncached.q_c);
+        }
+        assert_eq!(windows.len(), 1);
+    }
+
     #[test]
     fn test_ecc_constraint_zero() {
         let res = gadget_tester(
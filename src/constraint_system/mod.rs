@@ -27,6 +27,7 @@ pub mod logic;
 pub mod range;
 
 pub use composer::StandardComposer;
+pub use ecc::scalar_mul::fixed_base::FixedBaseWindows;
 pub use ecc::Point;
 pub use variable::Variable;
 pub(crate) use variable::WireData;
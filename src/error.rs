@@ -6,20 +6,23 @@
 
 //! A collection of all possible errors encountered in PLONK.
 
-use dusk_bytes::Error as DuskBytesError;
+use dusk_bytes::{BadLength, Error as DuskBytesError};
 
 /// Defines all possible errors that can be encountered in PLONK.
 #[derive(core::fmt::Debug)]
 pub enum Error {
     // FFT errors
-    /// This error occurs when an error triggers on any of the fft module
-    /// functions.
+    /// This error occurs when an evaluation domain is requested for more
+    /// coefficients than the largest supported domain holds.
     InvalidEvalDomainSize {
-        /// Log size of the group
-        log_size_of_group: u32,
-        /// Two adacity generated
-        adacity: u32,
+        /// Number of coefficients the domain was requested for
+        requested: usize,
+        /// Size of the largest supported domain
+        max_supported: u64,
     },
+    /// This error occurs when evaluations combined pointwise are not taken
+    /// over the same domain.
+    MismatchedEvaluations,
 
     // Prover/Verifier errors
     /// This error occurs when a proof verification fails.
@@ -32,10 +35,40 @@ pub enum Error {
     UninitializedPIGenerator,
     /// PublicInput serialization error
     InvalidPublicInputBytes,
+    /// This error occurs when the bytes given as a verifier key cannot be
+    /// deserialized.
+    VerifierKeyDecode,
+    /// This error occurs when the bytes given as a proof cannot be
+    /// deserialized.
+    ProofDecode,
+    /// This error occurs when the positions of the public inputs do not
+    /// match their values in number.
+    MismatchedPublicInputs {
+        /// Number of positions
+        positions: usize,
+        /// Number of values
+        values: usize,
+    },
+    /// This error occurs when a public input is placed outside of the
+    /// evaluation domain.
+    PublicInputPositionOutOfRange {
+        /// Position of the public input
+        position: usize,
+        /// Size of the domain
+        size: usize,
+    },
     /// This error occurs when the Prover structure already contains a
     /// preprocessed circuit inside, but you call preprocess again.
     CircuitAlreadyPreprocessed,
 
+    /// This error occurs when the numerator of the quotient polynomial does
+    /// not divide evenly by the vanishing polynomial, either because the
+    /// witness does not satisfy the circuit or because of a faulty prover.
+    QuotientNotDivisible {
+        /// Degree of the computed quotient
+        degree: usize,
+    },
+
     // Preprocessing errors
     /// This error occurs when an error triggers during the preprocessing
     /// stage.
@@ -53,7 +86,12 @@ pub enum Error {
     TruncatedDegreeIsZero,
     /// This error occurs when the user tries to commit to a polynomial whose
     /// degree is larger than the supported degree for that proving key.
-    PolynomialDegreeTooLarge,
+    PolynomialDegreeTooLarge {
+        /// Degree of the polynomial
+        degree: usize,
+        /// Max degree supported by the key
+        max: usize,
+    },
     /// This error occurs when the user tries to commit to a polynomial whose
     /// degree is zero.
     PolynomialDegreeIsZero,
@@ -72,20 +110,107 @@ pub enum Error {
     /// This error occurs when a malformed BLS scalar is decoded from a byte
     /// array.
     BlsScalarMalformed,
+    /// This error occurs when a deserialized circuit size does not fit in a
+    /// `usize` or cannot be padded to the next power of two on this target.
+    CircuitSizeOverflow {
+        /// Circuit size found in the serialized key
+        n: u64,
+    },
+    /// This error occurs when a serialized prover key is shorter than the
+    /// blocks announced by its header.
+    TruncatedProverKey {
+        /// Number of bytes required by the blocks left to read
+        expected: usize,
+        /// Number of bytes left in the buffer
+        available: usize,
+    },
+    /// This error occurs when a verifier key is built for a circuit of size
+    /// zero.
+    CircuitSizeIsZero,
+    /// This error occurs when a verifier key describes a circuit whose
+    /// arithmetic selectors are all committed to the zero polynomial.
+    DegenerateCircuit,
+    /// This error occurs when a serialized sub-key is loaded into a key
+    /// built for a circuit of another size.
+    MismatchedCircuitSize {
+        /// Circuit size of the key being updated
+        expected: usize,
+        /// Circuit size found in the serialized sub-key
+        found: usize,
+    },
+    /// This error occurs when verifier keys whose proofs are to be
+    /// aggregated are padded to different circuit sizes.
+    IncompatibleVerifierKeys {
+        /// Padded circuit size of the first key
+        size: usize,
+        /// Padded circuit size of the other key
+        other_size: usize,
+    },
+    /// This error occurs when serialized evaluations are loaded over an
+    /// evaluation domain of another size.
+    MismatchedEvalDomainSize {
+        /// Size of the provided domain
+        domain: usize,
+        /// Number of serialized evaluations
+        evaluations: usize,
+    },
+    /// This error occurs when a selector shared between widgets of a prover
+    /// key differs from one widget to another.
+    InconsistentSharedSelector {
+        /// Name of the shared selector
+        selector: &'static str,
+    },
+    /// This error occurs when a commitment is not a point of the
+    /// prime-order subgroup of the BLS12-381 G1 curve.
+    PointNotOnCurve {
+        /// Position of the commitment
+        index: usize,
+    },
+    /// This error occurs when a selector or sigma polynomial of a prover key
+    /// has a degree that is not lower than the circuit size.
+    SelectorDegreeTooHigh {
+        /// Name of the polynomial
+        name: &'static str,
+        /// Degree of the polynomial
+        degree: usize,
+        /// Circuit size
+        n: usize,
+    },
+    /// This error occurs when the sigma polynomials of a prover key do not
+    /// encode a permutation of the wire positions.
+    InvalidPermutation,
+    /// This error occurs when a prover key is truncated to a size that is
+    /// not a power of two no larger than its own.
+    InvalidTruncationSize {
+        /// Circuit size of the key
+        n: usize,
+        /// Requested circuit size
+        new_n: usize,
+    },
+    /// This error occurs when a selector of a prover key is not zero beyond
+    /// the size it is truncated to, or a copy constraint crosses it.
+    TruncatedSupport {
+        /// Name of the polynomial
+        name: &'static str,
+        /// Requested circuit size
+        new_n: usize,
+    },
+    /// This error occurs when a transcript is seeded with a digest that is
+    /// not the one of the verifier key.
+    MismatchedVerifierKeyDigest,
 }
 
-#[cfg(feature = "std")]
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::InvalidEvalDomainSize {
-                log_size_of_group,
-                adacity,
+                requested,
+                max_supported,
             } => write!(
                 f,
-                "Log-size of the EvaluationDomain group > TWO_ADACITY\
-            Size: {:?} > TWO_ADACITY = {:?}",
-                log_size_of_group, adacity
+                "EvaluationDomain for {} coefficients exceeds the maximum \
+                 supported size {}",
+                requested, max_supported
             ),
             Self::ProofVerificationError => {
                 write!(f, "proof verification failed")
@@ -99,6 +224,31 @@ impl std::fmt::Display for Error {
             Self::InvalidPublicInputBytes => {
                 write!(f, "invalid public input bytes")
             }
+            Self::VerifierKeyDecode => {
+                write!(f, "verifier key bytes could not be deserialized")
+            }
+            Self::ProofDecode => {
+                write!(f, "proof bytes could not be deserialized")
+            }
+            Self::MismatchedPublicInputs { positions, values } => write!(
+                f,
+                "{} public input positions given for {} values",
+                positions, values
+            ),
+            Self::PublicInputPositionOutOfRange { position, size } => write!(
+                f,
+                "public input position {} is out of a domain of size {}",
+                position, size
+            ),
+            Self::MismatchedEvaluations => {
+                write!(f, "evaluations are not taken over the same domain")
+            }
+            Self::QuotientNotDivisible { degree } => write!(
+                f,
+                "quotient of degree {} does not divide by the vanishing \
+                 polynomial: the witness does not satisfy the circuit",
+                degree
+            ),
             Self::MismatchedPolyLen => {
                 write!(f, "the length of the wires is not the same")
             }
@@ -115,9 +265,11 @@ impl std::fmt::Display for Error {
                 f,
                 "cannot trim PublicParameters to a maximum size of zero"
             ),
-            Self::PolynomialDegreeTooLarge => write!(
+            Self::PolynomialDegreeTooLarge { degree, max } => write!(
                 f,
-                "proving key is not large enough to commit to said polynomial"
+                "cannot commit to a polynomial of degree {} with a key of max \
+                 degree {}",
+                degree, max
             ),
             Self::PolynomialDegreeIsZero => {
                 write!(f, "cannot commit to polynomial of zero degree")
@@ -126,7 +278,91 @@ impl std::fmt::Display for Error {
             Self::NotEnoughBytes => write!(f, "not enough bytes left to read"),
             Self::PointMalformed => write!(f, "BLS point bytes malformed"),
             Self::BlsScalarMalformed => write!(f, "BLS scalar bytes malformed"),
-            Self::BytesError(err) => write!(f, "{:?}", err),
+            Self::CircuitSizeOverflow { n } => write!(
+                f,
+                "circuit size {} does not fit in a usize on this target",
+                n
+            ),
+            Self::TruncatedProverKey {
+                expected,
+                available,
+            } => write!(
+                f,
+                "prover key is truncated: expected {} bytes, found {}",
+                expected, available
+            ),
+            Self::CircuitSizeIsZero => {
+                write!(f, "cannot build a verifier key for an empty circuit")
+            }
+            Self::DegenerateCircuit => write!(
+                f,
+                "all arithmetic selector commitments are the identity"
+            ),
+            Self::MismatchedCircuitSize { expected, found } => write!(
+                f,
+                "sub-key of a circuit of size {} loaded into a key of size {}",
+                found, expected
+            ),
+            Self::IncompatibleVerifierKeys { size, other_size } => write!(
+                f,
+                "verifier keys padded to circuit sizes {} and {} cannot be \
+                 aggregated",
+                size, other_size
+            ),
+            Self::MismatchedEvalDomainSize {
+                domain,
+                evaluations,
+            } => write!(
+                f,
+                "{} evaluations cannot be taken over a domain of size {}",
+                evaluations, domain
+            ),
+            Self::InconsistentSharedSelector { selector } => write!(
+                f,
+                "selector {} is not the same in every widget using it",
+                selector
+            ),
+            Self::PointNotOnCurve { index } => write!(
+                f,
+                "commitment {} is not a point of the G1 subgroup",
+                index
+            ),
+            Self::SelectorDegreeTooHigh { name, degree, n } => write!(
+                f,
+                "polynomial {} has degree {}, not lower than circuit size {}",
+                name, degree, n
+            ),
+            Self::InvalidPermutation => write!(
+                f,
+                "sigma polynomials do not encode a permutation of the wire \
+                 positions"
+            ),
+            Self::InvalidTruncationSize { n, new_n } => write!(
+                f,
+                "a key of circuit size {} cannot be truncated to size {}",
+                n, new_n
+            ),
+            Self::TruncatedSupport { name, new_n } => write!(
+                f,
+                "polynomial {} does not fit in the first {} rows",
+                name, new_n
+            ),
+            Self::MismatchedVerifierKeyDigest => {
+                write!(f, "digest does not match the verifier key")
+            }
+            Self::BytesError(DuskBytesError::InvalidData) => {
+                write!(f, "invalid data in bytes")
+            }
+            Self::BytesError(DuskBytesError::BadLength { found, expected }) => {
+                write!(
+                    f,
+                    "bad length: expected {} bytes, found {}",
+                    expected, found
+                )
+            }
+            Self::BytesError(DuskBytesError::InvalidChar { ch, index }) => {
+                write!(f, "invalid char {:?} at index {}", ch, index)
+            }
         }
     }
 }
@@ -137,5 +373,36 @@ impl From<DuskBytesError> for Error {
     }
 }
 
+impl BadLength for Error {
+    fn bad_length(found: usize, expected: usize) -> Self {
+        Self::BytesError(DuskBytesError::bad_length(found, expected))
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn display_names_sizes() {
+        let err = Error::TruncatedProverKey {
+            expected: 96,
+            available: 40,
+        };
+        assert_eq!(
+            err.to_string(),
+            "prover key is truncated: expected 96 bytes, found 40"
+        );
+
+        let err: Error = DuskBytesError::bad_length(3, 32).into();
+        assert_eq!(err.to_string(), "bad length: expected 32 bytes, found 3");
+
+        let err = Error::CircuitSizeOverflow { n: u64::MAX };
+        assert!(err.to_string().contains(&u64::MAX.to_string()));
+    }
+}
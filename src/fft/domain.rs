@@ -19,7 +19,7 @@ use dusk_bytes::{DeserializableSlice, Serializable};
 /// only for fields that have a large multiplicative subgroup of size that is
 /// a power-of-2.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub(crate) struct EvaluationDomain {
+pub struct EvaluationDomain {
     /// The size of the domain.
     pub(crate) size: u64,
     /// `log_2(self.size)`.
@@ -82,6 +82,14 @@ impl Serializable<{ u64::SIZE + u32::SIZE + 5 * BlsScalar::SIZE }>
     }
 }
 
+impl EvaluationDomain {
+    /// Return the generator `g` of the multiplicative subgroup of `self`,
+    /// so that its `i`-th element is `g^i`.
+    pub fn group_gen(&self) -> BlsScalar {
+        self.group_gen
+    }
+}
+
 #[cfg(feature = "alloc")]
 pub(crate) mod alloc {
 
@@ -97,18 +105,26 @@ pub(crate) mod alloc {
     impl EvaluationDomain {
         /// Construct a domain that is large enough for evaluations of a
         /// polynomial having `num_coeffs` coefficients.
-        pub(crate) fn new(num_coeffs: usize) -> Result<Self, Error> {
+        ///
+        /// The size of the domain is `num_coeffs` rounded up to the next
+        /// power of two. The scalar field of BLS12-381 has a two-adicity of
+        /// `32`, but the FFTs index the domain with 32-bit integers, so the
+        /// largest supported domain has `2^31` elements. Larger requests
+        /// return [`Error::InvalidEvalDomainSize`].
+        pub fn new(num_coeffs: usize) -> Result<Self, Error> {
             // Compute the size of our evaluation domain
-            let size = num_coeffs.next_power_of_two() as u64;
+            let max_supported = 1u64 << (TWO_ADACITY - 1);
+            let size = match (num_coeffs as u64).checked_next_power_of_two() {
+                Some(size) if size <= max_supported => size,
+                _ => {
+                    return Err(Error::InvalidEvalDomainSize {
+                        requested: num_coeffs,
+                        max_supported,
+                    })
+                }
+            };
             let log_size_of_group = size.trailing_zeros();
 
-            if log_size_of_group >= TWO_ADACITY {
-                return Err(Error::InvalidEvalDomainSize {
-                    log_size_of_group,
-                    adacity: TWO_ADACITY,
-                });
-            }
-
             // Compute the generator for the multiplicative subgroup.
             // It should be 2^(log_size_of_group) root of unity.
 
@@ -131,7 +147,7 @@ pub(crate) mod alloc {
         }
 
         /// Return the size of `self`.
-        pub(crate) fn size(&self) -> usize {
+        pub fn size(&self) -> usize {
             self.size as usize
         }
 
@@ -290,8 +306,9 @@ pub(crate) mod alloc {
             Evaluations::from_vec_and_domain(v_h, *self)
         }
 
-        /// Return an iterator over the elements of the domain.
-        pub(crate) fn elements(&self) -> Elements {
+        /// Return an iterator over the elements of the domain, in order
+        /// `g^0, g^1, ..., g^(size - 1)` for the generator `g`.
+        pub fn elements(&self) -> Elements {
             Elements {
                 cur_elem: BlsScalar::one(),
                 cur_pow: 0,
@@ -358,7 +375,7 @@ pub(crate) mod alloc {
 
     /// An iterator over the elements of the domain.
     #[derive(Debug)]
-    pub(crate) struct Elements {
+    pub struct Elements {
         cur_elem: BlsScalar,
         cur_pow: u64,
         domain: EvaluationDomain,
@@ -382,6 +399,7 @@ pub(crate) mod alloc {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
 
     #[test]
     fn size_of_elements() {
@@ -404,6 +422,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn elements_match_group_gen_powers() {
+        let domain = EvaluationDomain::new(1 << 5).unwrap();
+        let g = domain.group_gen();
+        for i in 0..domain.size() {
+            assert_eq!(
+                domain.elements().nth(i),
+                Some(g.pow(&[i as u64, 0, 0, 0]))
+            );
+        }
+        assert_eq!(domain.elements().nth(domain.size()), None);
+        assert_eq!(g.pow(&[domain.size() as u64, 0, 0, 0]), BlsScalar::one());
+    }
+
+    #[test]
+    fn evaluation_domain_max_size() {
+        let max = 1 << 31;
+        let domain = EvaluationDomain::new(max).unwrap();
+        assert_eq!(domain.size(), max);
+        assert_eq!(
+            domain.group_gen.pow(&[max as u64, 0, 0, 0]),
+            BlsScalar::one()
+        );
+        assert_ne!(
+            domain.group_gen.pow(&[max as u64 / 2, 0, 0, 0]),
+            BlsScalar::one()
+        );
+
+        for requested in [max + 1, usize::MAX] {
+            assert!(matches!(
+                EvaluationDomain::new(requested),
+                Err(Error::InvalidEvalDomainSize {
+                    requested: r,
+                    max_supported: 0x8000_0000,
+                }) if r == requested
+            ));
+        }
+    }
+
     #[test]
     fn dusk_bytes_evaluation_domain_serde() {
         let eval_domain = EvaluationDomain::new(1 << 13 - 1)
@@ -18,7 +18,7 @@ use dusk_bytes::{DeserializableSlice, Serializable};
 
 /// Stores a polynomial in evaluation form.
 #[derive(PartialEq, Eq, Debug, Clone)]
-pub(crate) struct Evaluations {
+pub struct Evaluations {
     /// The evaluations of a polynomial over the domain `D`
     pub(crate) evals: Vec<BlsScalar>,
     #[doc(hidden)]
@@ -59,12 +59,63 @@ impl Evaluations {
         Self { evals, domain }
     }
 
+    /// Returns the domain the evaluations are taken over.
+    pub(crate) const fn domain(&self) -> &EvaluationDomain {
+        &self.domain
+    }
+
     /// Interpolate a polynomial from a list of evaluations
     pub(crate) fn interpolate(self) -> Polynomial {
         let Self { mut evals, domain } = self;
         domain.ifft_in_place(&mut evals);
         Polynomial::from_coefficients_vec(evals)
     }
+
+    /// Multiplies `self` and `other` pointwise.
+    ///
+    /// Returns [`Error::MismatchedEvaluations`] if they are not taken over
+    /// the same domain.
+    pub fn mul_pointwise(
+        &self,
+        other: &Evaluations,
+    ) -> Result<Evaluations, Error> {
+        self.check_same_domain(other)?;
+        Ok(self * other)
+    }
+
+    /// Adds `self` and `other` pointwise.
+    ///
+    /// Returns [`Error::MismatchedEvaluations`] if they are not taken over
+    /// the same domain.
+    pub fn add_pointwise(
+        &self,
+        other: &Evaluations,
+    ) -> Result<Evaluations, Error> {
+        self.check_same_domain(other)?;
+        Ok(self + other)
+    }
+
+    /// Multiplies every evaluation of `self` by `factor`.
+    pub fn scale(&self, factor: BlsScalar) -> Evaluations {
+        let evals = self.evals.iter().map(|eval| eval * factor).collect();
+        Evaluations::from_vec_and_domain(evals, self.domain)
+    }
+
+    fn check_same_domain(&self, other: &Evaluations) -> Result<(), Error> {
+        if self.domain != other.domain || self.evals.len() != other.evals.len()
+        {
+            return Err(Error::MismatchedEvaluations);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Evaluations {
+    fn zeroize(&mut self) {
+        crate::util::zeroize_scalars(&mut self.evals);
+    }
 }
 
 impl Index<usize> for Evaluations {
@@ -151,3 +202,40 @@ impl<'a> DivAssign<&'a Evaluations> for Evaluations {
             .for_each(|(a, b)| *a *= b.invert().unwrap());
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pointwise_operations() {
+        let domain = EvaluationDomain::new(4).unwrap();
+        let evals = |values: [u64; 4]| {
+            Evaluations::from_vec_and_domain(
+                values.iter().map(|&v| BlsScalar::from(v)).collect(),
+                domain,
+            )
+        };
+        let a = evals([1, 2, 3, 4]);
+        let b = evals([5, 6, 7, 8]);
+
+        assert_eq!(a.mul_pointwise(&b).unwrap(), evals([5, 12, 21, 32]));
+        assert_eq!(a.add_pointwise(&b).unwrap(), evals([6, 8, 10, 12]));
+        assert_eq!(a.scale(BlsScalar::from(3)), evals([3, 6, 9, 12]));
+
+        let other_domain = Evaluations::from_vec_and_domain(
+            b.evals.clone(),
+            EvaluationDomain::new(8).unwrap(),
+        );
+        assert!(matches!(
+            a.mul_pointwise(&other_domain),
+            Err(Error::MismatchedEvaluations)
+        ));
+        let shorter =
+            Evaluations::from_vec_and_domain(b.evals[..3].to_vec(), domain);
+        assert!(matches!(
+            a.add_pointwise(&shorter),
+            Err(Error::MismatchedEvaluations)
+        ));
+    }
+}
@@ -15,10 +15,10 @@ if #[cfg(feature = "alloc")]
     pub(crate) use domain::alloc::*;
     pub(crate) mod evaluations;
     pub(crate) mod polynomial;
-    pub(crate) use evaluations::Evaluations;
-    pub(crate) use polynomial::Polynomial;
+    pub use domain::alloc::Elements;
+    pub use evaluations::Evaluations;
+    pub use polynomial::Polynomial;
 });
 
 pub(crate) mod domain;
-#[allow(unused_imports)]
-pub(crate) use domain::EvaluationDomain;
+pub use domain::EvaluationDomain;
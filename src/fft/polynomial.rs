@@ -9,7 +9,6 @@
 //! vector.
 use super::{EvaluationDomain, Evaluations};
 use crate::error::Error;
-use crate::util;
 use alloc::vec::Vec;
 use core::ops::{Add, AddAssign, Deref, DerefMut, Mul, Neg, Sub, SubAssign};
 use dusk_bls12_381::BlsScalar;
@@ -17,11 +16,18 @@ use dusk_bytes::{DeserializableSlice, Serializable};
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 /// Represents a polynomial in coeffiient form.
-pub(crate) struct Polynomial {
+pub struct Polynomial {
     /// The coefficient of `x^i` is stored at location `i` in `self.coeffs`.
     pub(crate) coeffs: Vec<BlsScalar>,
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Polynomial {
+    fn zeroize(&mut self) {
+        crate::util::zeroize_scalars(&mut self.coeffs);
+    }
+}
+
 impl Deref for Polynomial {
     type Target = [BlsScalar];
 
@@ -61,7 +67,7 @@ impl Polynomial {
         let mut result = Self { coeffs };
         // While there are zeros at the end of the coefficient vector, pop them
         // off.
-        result.truncate_leading_zeros();
+        result.trim_trailing_zeros();
         // Check that either the coefficients vec is empty or that the last
         // coeff is non-zero.
         assert!(result
@@ -72,19 +78,28 @@ impl Polynomial {
         result
     }
 
-    /// Returns the degree of the [`Polynomial`].
-    pub(crate) fn degree(&self) -> usize {
-        if self.is_zero() {
-            return 0;
-        }
-        assert!(self
-            .coeffs
-            .last()
-            .map_or(false, |coeff| coeff != &BlsScalar::zero()));
-        self.coeffs.len() - 1
+    /// Returns the degree of the [`Polynomial`], that is the index of its
+    /// highest non-zero coefficient, or zero for the zero polynomial.
+    pub fn degree(&self) -> usize {
+        self.coeffs
+            .iter()
+            .rposition(|coeff| coeff != &BlsScalar::zero())
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of non-zero coefficients of the [`Polynomial`].
+    pub fn nonzero_count(&self) -> usize {
+        self.coeffs
+            .iter()
+            .filter(|coeff| *coeff != &BlsScalar::zero())
+            .count()
     }
 
-    fn truncate_leading_zeros(&mut self) {
+    /// Removes the zero coefficients of the highest degrees.
+    ///
+    /// The trimmed [`Polynomial`] evaluates identically at every point, so
+    /// its commitment and its evaluations over any domain are unchanged.
+    pub fn trim_trailing_zeros(&mut self) {
         while self
             .coeffs
             .last()
@@ -94,25 +109,15 @@ impl Polynomial {
         }
     }
 
-    /// Evaluates a [`Polynomial`] at a given point in the field.
-    pub(crate) fn evaluate(&self, point: &BlsScalar) -> BlsScalar {
-        if self.is_zero() {
-            return BlsScalar::zero();
-        }
-
-        // Compute powers of points
-        let powers = util::powers_of(point, self.len());
-
-        let p_evals: Vec<_> = self
-            .iter()
-            .zip(powers.into_iter())
-            .map(|(c, p)| p * c)
-            .collect();
-        let mut sum = BlsScalar::zero();
-        for eval in p_evals.into_iter() {
-            sum += &eval;
-        }
-        sum
+    /// Evaluates a [`Polynomial`] at a given point in the field, with
+    /// Horner's method.
+    ///
+    /// The selectors of a `ProverKey` can be read at row `i` of the circuit
+    /// by evaluating them at `\omega^i`, the `i`-th element of the domain.
+    pub fn evaluate(&self, point: &BlsScalar) -> BlsScalar {
+        self.iter()
+            .rev()
+            .fold(BlsScalar::zero(), |acc, coeff| acc * point + coeff)
     }
 
     /// Given a [`Polynomial`], return it in it's bytes representation
@@ -134,6 +139,25 @@ impl Polynomial {
 
         Ok(Polynomial { coeffs })
     }
+
+    /// Interpolates the [`Polynomial`] whose evaluations over the domain of
+    /// `evals` are `evals`.
+    pub fn interpolate(evals: &Evaluations) -> Polynomial {
+        evals.clone().interpolate()
+    }
+
+    /// Evaluates `self` over every element of `domain`, in the order given
+    /// by [`EvaluationDomain::elements`].
+    ///
+    /// # Panics
+    /// When `self` has more coefficients than `domain` has elements.
+    pub fn evaluate_over_domain(
+        &self,
+        domain: &EvaluationDomain,
+    ) -> Evaluations {
+        assert!(self.coeffs.len() <= domain.size());
+        Evaluations::from_vec_and_domain(domain.fft(&self.coeffs), *domain)
+    }
 }
 
 use core::iter::Sum;
@@ -172,7 +196,7 @@ impl<'a, 'b> Add<&'a Polynomial> for &'b Polynomial {
             }
             result
         };
-        result.truncate_leading_zeros();
+        result.trim_trailing_zeros();
         result
     }
 }
@@ -193,7 +217,7 @@ impl<'a, 'b> AddAssign<&'a Polynomial> for Polynomial {
             for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
                 *a += b
             }
-            self.truncate_leading_zeros();
+            self.trim_trailing_zeros();
         }
     }
 }
@@ -215,7 +239,7 @@ impl<'a, 'b> AddAssign<(BlsScalar, &'a Polynomial)> for Polynomial {
             for (a, b) in self.coeffs.iter_mut().zip(&other.coeffs) {
                 *a += &(f * b);
             }
-            self.truncate_leading_zeros();
+            self.trim_trailing_zeros();
         }
     }
 }
@@ -259,7 +283,7 @@ impl<'a, 'b> Sub<&'a Polynomial> for &'b Polynomial {
             }
             result
         };
-        result.truncate_leading_zeros();
+        result.trim_trailing_zeros();
         result
     }
 }
@@ -284,7 +308,7 @@ impl<'a, 'b> SubAssign<&'a Polynomial> for Polynomial {
                 *a -= b
             }
             // If the leading coefficient ends up being zero, pop it off.
-            self.truncate_leading_zeros();
+            self.trim_trailing_zeros();
         }
     }
 }
@@ -400,6 +424,7 @@ impl<'a, 'b> Sub<&'a BlsScalar> for &'b Polynomial {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::util;
     use rand_core::{CryptoRng, RngCore};
 
     impl Polynomial {
@@ -420,6 +445,76 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_degree_nonzero_count() {
+        assert_eq!(Polynomial::zero().degree(), 0);
+        assert_eq!(Polynomial::zero().nonzero_count(), 0);
+
+        // 3X^4 + 1, with trailing zeros left in place
+        let mut coeffs = vec![BlsScalar::zero(); 7];
+        coeffs[0] = BlsScalar::one();
+        coeffs[4] = BlsScalar::from(3);
+        let p = Polynomial { coeffs };
+        assert_eq!(p.degree(), 4);
+        assert_eq!(p.nonzero_count(), 2);
+    }
+
+    #[test]
+    fn test_trim_trailing_zeros() {
+        let mut p = Polynomial::rand(10, &mut rand_core::OsRng);
+        let trimmed = p.clone();
+        p.coeffs.extend_from_slice(&[BlsScalar::zero(); 5]);
+
+        let point = BlsScalar::from(7);
+        let eval = p.evaluate(&point);
+        p.trim_trailing_zeros();
+        assert_eq!(p, trimmed);
+        assert_eq!(p.evaluate(&point), eval);
+
+        let mut zero = Polynomial {
+            coeffs: vec![BlsScalar::zero(); 3],
+        };
+        zero.trim_trailing_zeros();
+        assert!(zero.coeffs.is_empty());
+    }
+
+    #[test]
+    fn test_interpolate_evaluate_over_domain() {
+        for (degree, size) in [(0, 1), (15, 16), (15, 64), (100, 128)] {
+            let p = Polynomial::rand(degree, &mut rand_core::OsRng);
+            let domain = EvaluationDomain::new(size).unwrap();
+            let evals = p.evaluate_over_domain(&domain);
+
+            for (x, eval) in domain.elements().zip(evals.evals.iter()) {
+                assert_eq!(&p.evaluate(&x), eval);
+            }
+            assert_eq!(Polynomial::interpolate(&evals), p);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_coset() {
+        use dusk_bls12_381::GENERATOR;
+
+        let p = Polynomial::rand(63, &mut rand_core::OsRng);
+        let domain = EvaluationDomain::new(4 * 64).unwrap();
+        let evals = domain.coset_fft(&p);
+
+        for (x, eval) in domain.elements().zip(evals.iter()) {
+            let point = GENERATOR * x;
+            assert_eq!(&p.evaluate(&point), eval);
+
+            let powers = util::powers_of(&point, p.len());
+            let sum = p.iter().zip(powers).map(|(c, x)| c * x).sum();
+            assert_eq!(p.evaluate(&point), sum);
+        }
+
+        assert_eq!(
+            Polynomial { coeffs: vec![] }.evaluate(&BlsScalar::one()),
+            BlsScalar::zero()
+        );
+    }
+
     #[test]
     fn test_ruffini() {
         // X^2 + 4X + 4
@@ -66,8 +66,13 @@ if #[cfg(feature = "alloc")] {
     pub mod circuit;
     mod util;
     mod permutation;
+    #[cfg(feature = "std")]
+    #[cfg(any(test, feature = "test-setup"))]
+    pub mod test_fixtures;
 });
 
+#[cfg(feature = "ark-interop")]
+pub mod ark_interop;
 pub mod commitment_scheme;
 pub mod error;
 mod fft;
@@ -87,6 +92,10 @@ pub mod notes {
     pub mod kzg10_docs {}
 }
 
+pub use fft::EvaluationDomain;
+#[cfg(feature = "alloc")]
+pub use fft::{Elements, Evaluations, Polynomial};
+
 /// Re-exported dusk-bls12_381 fork.
 pub use dusk_bls12_381 as bls12_381;
 
@@ -208,16 +208,19 @@ pub(crate) fn compute(
         prover_key,
     );
 
-    let f_2 = prover_key.permutation.compute_linearisation(
-        z_challenge,
-        (alpha, beta, gamma),
-        (&a_eval, &b_eval, &c_eval, &d_eval),
-        (&left_sigma_eval, &right_sigma_eval, &out_sigma_eval),
-        &perm_eval,
-        z_poly,
-    );
-
-    let lin_poly = &f_1 + &f_2;
+    let lin_poly = if prover_key.permutation_enabled() {
+        let f_2 = prover_key.permutation.compute_linearisation(
+            z_challenge,
+            (alpha, beta, gamma),
+            (&a_eval, &b_eval, &c_eval, &d_eval),
+            (&left_sigma_eval, &right_sigma_eval, &out_sigma_eval),
+            &perm_eval,
+            z_poly,
+        );
+        &f_1 + &f_2
+    } else {
+        f_1
+    };
 
     // Evaluate linearisation polynomial at z_challenge
     let lin_poly_eval = lin_poly.evaluate(z_challenge);
@@ -9,6 +9,9 @@
 cfg_if::cfg_if!(
     if #[cfg(feature = "alloc")] {
         mod preprocess;
+        pub(crate) mod profiling;
+        #[cfg(feature = "profiling")]
+        pub use profiling::ProvingProfile;
         /// Represents a PLONK Prover
         pub mod prover;
         pub use proof::alloc::*;
@@ -16,7 +19,7 @@ cfg_if::cfg_if!(
         /// Represents a PLONK Verifier
         pub mod verifier;
         pub use prover::Prover;
-        pub use verifier::Verifier;
+        pub use verifier::{BatchVerifier, Verifier, VerifierContext};
         pub use widget::alloc::*;
     }
 );
@@ -24,6 +27,8 @@ cfg_if::cfg_if!(
 /// Represents a PLONK Proof
 pub mod proof;
 pub(crate) mod widget;
-pub use proof::Proof;
-pub use widget::VerifierKey;
+pub use proof::{proof_size_bytes, Proof, PROOF_SIZE};
+#[cfg(feature = "ffi")]
+pub use widget::VerifierKeyC;
+pub use widget::{VerifierKey, VerifierKeyField};
 pub(crate) mod linearisation_poly;
@@ -12,6 +12,7 @@ use crate::constraint_system::StandardComposer;
 use crate::error::Error;
 use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
 use crate::proof_system::{widget, ProverKey};
+use alloc::vec::Vec;
 use dusk_bls12_381::BlsScalar;
 use merlin::Transcript;
 
@@ -102,72 +103,80 @@ impl StandardComposer {
         &mut self,
         commit_key: &CommitKey,
         transcript: &mut Transcript,
+    ) -> Result<ProverKey, Error> {
+        self.preprocess_prover_with(commit_key, transcript, None, false)
+    }
+
+    /// Same as [`StandardComposer::preprocess_prover`], but seeds the
+    /// transcript with the `digest` of the verifier key through
+    /// `VerifierKey::seed_transcript_digest`.
+    pub(crate) fn preprocess_prover_with_digest(
+        &mut self,
+        commit_key: &CommitKey,
+        transcript: &mut Transcript,
+        digest: &[u8; 32],
+    ) -> Result<ProverKey, Error> {
+        self.preprocess_prover_with(commit_key, transcript, Some(digest), false)
+    }
+
+    /// Computes the [`ProverKey`], optionally distributing the selector
+    /// transforms across the rayon thread pool.
+    fn preprocess_prover_with(
+        &mut self,
+        commit_key: &CommitKey,
+        transcript: &mut Transcript,
+        digest: Option<&[u8; 32]>,
+        parallel: bool,
     ) -> Result<ProverKey, Error> {
         let (_, selectors, domain) =
-            self.preprocess_shared(commit_key, transcript)?;
+            self.preprocess_shared(commit_key, transcript, digest, parallel)?;
 
         let domain_4n = EvaluationDomain::new(4 * domain.size())?;
-        let q_m_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_m),
-            domain_4n,
-        );
-        let q_l_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_l),
-            domain_4n,
-        );
-        let q_r_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_r),
-            domain_4n,
-        );
-        let q_o_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_o),
-            domain_4n,
-        );
-        let q_c_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_c),
-            domain_4n,
-        );
-        let q_4_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_4),
-            domain_4n,
-        );
-        let q_arith_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_arith),
-            domain_4n,
-        );
-        let q_range_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_range),
-            domain_4n,
-        );
-        let q_logic_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_logic),
-            domain_4n,
-        );
-        let q_fixed_group_add_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_fixed_group_add),
-            domain_4n,
-        );
-        let q_variable_group_add_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.q_variable_group_add),
-            domain_4n,
-        );
+        let polys = [
+            &selectors.q_m,
+            &selectors.q_l,
+            &selectors.q_r,
+            &selectors.q_o,
+            &selectors.q_c,
+            &selectors.q_4,
+            &selectors.q_arith,
+            &selectors.q_range,
+            &selectors.q_logic,
+            &selectors.q_fixed_group_add,
+            &selectors.q_variable_group_add,
+            &selectors.left_sigma,
+            &selectors.right_sigma,
+            &selectors.out_sigma,
+            &selectors.fourth_sigma,
+        ];
+        let mut evals_4n = map_selectors(&polys, parallel, |poly| {
+            Evaluations::from_vec_and_domain(
+                domain_4n.coset_fft(poly),
+                domain_4n,
+            )
+        })
+        .into_iter();
+        let mut next = || {
+            evals_4n
+                .next()
+                .expect("one evaluation per selector polynomial")
+        };
+        let q_m_eval_4n = next();
+        let q_l_eval_4n = next();
+        let q_r_eval_4n = next();
+        let q_o_eval_4n = next();
+        let q_c_eval_4n = next();
+        let q_4_eval_4n = next();
+        let q_arith_eval_4n = next();
+        let q_range_eval_4n = next();
+        let q_logic_eval_4n = next();
+        let q_fixed_group_add_eval_4n = next();
+        let q_variable_group_add_eval_4n = next();
+        let left_sigma_eval_4n = next();
+        let right_sigma_eval_4n = next();
+        let out_sigma_eval_4n = next();
+        let fourth_sigma_eval_4n = next();
 
-        let left_sigma_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.left_sigma),
-            domain_4n,
-        );
-        let right_sigma_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.right_sigma),
-            domain_4n,
-        );
-        let out_sigma_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.out_sigma),
-            domain_4n,
-        );
-        let fourth_sigma_eval_4n = Evaluations::from_vec_and_domain(
-            domain_4n.coset_fft(&selectors.fourth_sigma),
-            domain_4n,
-        );
         // XXX: Remove this and compute it on the fly
         let linear_eval_4n = Evaluations::from_vec_and_domain(
             domain_4n.coset_fft(&[BlsScalar::zero(), BlsScalar::one()]),
@@ -236,7 +245,10 @@ impl StandardComposer {
             // Compute 4n evaluations for X^n -1
             v_h_coset_4n: domain_4n
                 .compute_vanishing_poly_over_coset(domain.size() as u64),
+            #[cfg(feature = "debug-unsound")]
+            permutation_disabled: false,
         };
+        prover_key.validate_degrees()?;
 
         Ok(prover_key)
     }
@@ -250,7 +262,25 @@ impl StandardComposer {
         transcript: &mut Transcript,
     ) -> Result<widget::VerifierKey, Error> {
         let (verifier_key, _, _) =
-            self.preprocess_shared(commit_key, transcript)?;
+            self.preprocess_shared(commit_key, transcript, None, false)?;
+        Ok(verifier_key)
+    }
+
+    /// Same as [`StandardComposer::preprocess_verifier`], but seeds the
+    /// transcript with the `digest` of the verifier key through
+    /// `VerifierKey::seed_transcript_digest`.
+    pub(crate) fn preprocess_verifier_with_digest(
+        &mut self,
+        commit_key: &CommitKey,
+        transcript: &mut Transcript,
+        digest: &[u8; 32],
+    ) -> Result<widget::VerifierKey, Error> {
+        let (verifier_key, _, _) = self.preprocess_shared(
+            commit_key,
+            transcript,
+            Some(digest),
+            false,
+        )?;
         Ok(verifier_key)
     }
 
@@ -262,6 +292,8 @@ impl StandardComposer {
         &mut self,
         commit_key: &CommitKey,
         transcript: &mut Transcript,
+        digest: Option<&[u8; 32]>,
+        parallel: bool,
     ) -> Result<
         (widget::VerifierKey, SelectorPolynomials, EvaluationDomain),
         Error,
@@ -274,30 +306,35 @@ impl StandardComposer {
         // 1. Pad circuit to a power of two
         self.pad(domain.size as usize - self.n);
 
-        let q_m_poly =
-            Polynomial::from_coefficients_slice(&domain.ifft(&self.q_m));
-        let q_l_poly =
-            Polynomial::from_coefficients_slice(&domain.ifft(&self.q_l));
-        let q_r_poly =
-            Polynomial::from_coefficients_slice(&domain.ifft(&self.q_r));
-        let q_o_poly =
-            Polynomial::from_coefficients_slice(&domain.ifft(&self.q_o));
-        let q_c_poly =
-            Polynomial::from_coefficients_slice(&domain.ifft(&self.q_c));
-        let q_4_poly =
-            Polynomial::from_coefficients_slice(&domain.ifft(&self.q_4));
-        let q_arith_poly =
-            Polynomial::from_coefficients_slice(&domain.ifft(&self.q_arith));
-        let q_range_poly =
-            Polynomial::from_coefficients_slice(&domain.ifft(&self.q_range));
-        let q_logic_poly =
-            Polynomial::from_coefficients_slice(&domain.ifft(&self.q_logic));
-        let q_fixed_group_add_poly = Polynomial::from_coefficients_slice(
-            &domain.ifft(&self.q_fixed_group_add),
-        );
-        let q_variable_group_add_poly = Polynomial::from_coefficients_slice(
-            &domain.ifft(&self.q_variable_group_add),
-        );
+        let evals = [
+            &self.q_m,
+            &self.q_l,
+            &self.q_r,
+            &self.q_o,
+            &self.q_c,
+            &self.q_4,
+            &self.q_arith,
+            &self.q_range,
+            &self.q_logic,
+            &self.q_fixed_group_add,
+            &self.q_variable_group_add,
+        ];
+        let mut polys = map_selectors(&evals, parallel, |evals| {
+            Polynomial::from_coefficients_vec(domain.ifft(evals))
+        })
+        .into_iter();
+        let mut next = || polys.next().expect("one polynomial per selector");
+        let q_m_poly = next();
+        let q_l_poly = next();
+        let q_r_poly = next();
+        let q_o_poly = next();
+        let q_c_poly = next();
+        let q_4_poly = next();
+        let q_arith_poly = next();
+        let q_range_poly = next();
+        let q_logic_poly = next();
+        let q_fixed_group_add_poly = next();
+        let q_variable_group_add_poly = next();
 
         // 2. Compute the sigma polynomials
         let (
@@ -307,24 +344,19 @@ impl StandardComposer {
             fourth_sigma_poly,
         ) = self.perm.compute_sigma_polynomials(self.n, &domain);
 
-        let q_m_poly_commit = commit_key.commit(&q_m_poly).unwrap_or_default();
-        let q_l_poly_commit = commit_key.commit(&q_l_poly).unwrap_or_default();
-        let q_r_poly_commit = commit_key.commit(&q_r_poly).unwrap_or_default();
-        let q_o_poly_commit = commit_key.commit(&q_o_poly).unwrap_or_default();
-        let q_c_poly_commit = commit_key.commit(&q_c_poly).unwrap_or_default();
-        let q_4_poly_commit = commit_key.commit(&q_4_poly).unwrap_or_default();
-        let q_arith_poly_commit =
-            commit_key.commit(&q_arith_poly).unwrap_or_default();
-        let q_range_poly_commit =
-            commit_key.commit(&q_range_poly).unwrap_or_default();
-        let q_logic_poly_commit =
-            commit_key.commit(&q_logic_poly).unwrap_or_default();
-        let q_fixed_group_add_poly_commit = commit_key
-            .commit(&q_fixed_group_add_poly)
-            .unwrap_or_default();
-        let q_variable_group_add_poly_commit = commit_key
-            .commit(&q_variable_group_add_poly)
-            .unwrap_or_default();
+        let q_m_poly_commit = commit_key.commit_selector(&q_m_poly)?;
+        let q_l_poly_commit = commit_key.commit_selector(&q_l_poly)?;
+        let q_r_poly_commit = commit_key.commit_selector(&q_r_poly)?;
+        let q_o_poly_commit = commit_key.commit_selector(&q_o_poly)?;
+        let q_c_poly_commit = commit_key.commit_selector(&q_c_poly)?;
+        let q_4_poly_commit = commit_key.commit_selector(&q_4_poly)?;
+        let q_arith_poly_commit = commit_key.commit_selector(&q_arith_poly)?;
+        let q_range_poly_commit = commit_key.commit_selector(&q_range_poly)?;
+        let q_logic_poly_commit = commit_key.commit_selector(&q_logic_poly)?;
+        let q_fixed_group_add_poly_commit =
+            commit_key.commit_selector(&q_fixed_group_add_poly)?;
+        let q_variable_group_add_poly_commit =
+            commit_key.commit_selector(&q_variable_group_add_poly)?;
 
         let left_sigma_poly_commit = commit_key.commit(&left_sigma_poly)?;
         let right_sigma_poly_commit = commit_key.commit(&right_sigma_poly)?;
@@ -378,6 +410,8 @@ impl StandardComposer {
             fixed_base: ecc_verifier_key,
             variable_base: curve_addition_verifier_key,
             permutation: permutation_verifier_key,
+            #[cfg(feature = "debug-unsound")]
+            permutation_disabled: false,
         };
 
         let selectors = SelectorPolynomials {
@@ -398,13 +432,52 @@ impl StandardComposer {
             fourth_sigma: fourth_sigma_poly,
         };
 
-        // Add the circuit description to the transcript
-        verifier_key.seed_transcript(transcript);
+        // Add the circuit description, or its digest, to the transcript
+        match digest {
+            Some(digest) => {
+                verifier_key.seed_transcript_digest(transcript, digest)?
+            }
+            None => verifier_key.seed_transcript(transcript),
+        }
 
         Ok((verifier_key, selectors, domain))
     }
 }
 
+#[cfg(feature = "std")]
+impl ProverKey {
+    /// Preprocesses the circuit like [`StandardComposer::preprocess_prover`],
+    /// distributing the selector interpolations and their `4n` coset
+    /// evaluations across the rayon thread pool.
+    ///
+    /// The resulting [`ProverKey`] is identical to the sequential one.
+    pub fn build_parallel(
+        composer: &mut StandardComposer,
+        commit_key: &CommitKey,
+        transcript: &mut Transcript,
+    ) -> Result<ProverKey, Error> {
+        composer.preprocess_prover_with(commit_key, transcript, None, true)
+    }
+}
+
+/// Applies `f` to each selector, in parallel when requested and supported.
+fn map_selectors<T, U, F>(items: &[T], parallel: bool, f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync + Send,
+{
+    #[cfg(feature = "std")]
+    if parallel {
+        use rayon::prelude::*;
+        return items.par_iter().map(f).collect();
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = parallel;
+
+    items.iter().map(f).collect()
+}
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod test {
@@ -437,4 +510,22 @@ mod test {
         assert!(composer.w_r.len() == size);
         assert!(composer.w_o.len() == size);
     }
+
+    #[test]
+    fn test_build_parallel_matches_sequential() {
+        use crate::test_fixtures::preprocessed_dummy_key;
+
+        let (commit_key, sequential) = preprocessed_dummy_key(100);
+
+        let mut composer = StandardComposer::new();
+        dummy_gadget(100, &mut composer);
+        let parallel = ProverKey::build_parallel(
+            &mut composer,
+            &commit_key,
+            &mut Transcript::new(b"test"),
+        )
+        .unwrap();
+
+        assert_eq!(sequential, parallel);
+    }
 }
@@ -0,0 +1,160 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Timing of the proving stages.
+//!
+//! Without the `profiling` feature the [`Recorder`] is a unit struct whose
+//! `time` method only calls the timed closure, so no timing code is compiled
+//! in.
+
+#[cfg(feature = "profiling")]
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "profiling")]
+use std::collections::HashMap;
+#[cfg(feature = "profiling")]
+use std::time::{Duration, Instant};
+
+/// Proving stage whose time is recorded.
+///
+/// Without the `profiling` feature the widgets of the circuit satisfiability
+/// equation are evaluated in a single untimed pass, so their stages are never
+/// constructed.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(not(feature = "profiling"), allow(dead_code))]
+pub(crate) enum Stage {
+    Arithmetic,
+    Range,
+    Logic,
+    FixedBase,
+    VariableBase,
+    Permutation,
+    Fft,
+    Msm,
+}
+
+impl Stage {
+    #[cfg(feature = "profiling")]
+    const COUNT: usize = 8;
+
+    #[cfg(feature = "profiling")]
+    const WIDGETS: [(Stage, &'static str); 6] = [
+        (Stage::Arithmetic, "arithmetic"),
+        (Stage::Range, "range"),
+        (Stage::Logic, "logic"),
+        (Stage::FixedBase, "fixed_base"),
+        (Stage::VariableBase, "variable_base"),
+        (Stage::Permutation, "permutation"),
+    ];
+}
+
+/// Time spent computing a [`Proof`](super::Proof), as returned by
+/// [`Prover::prove_profiled`](super::Prover::prove_profiled).
+///
+/// The durations are wall time: each stage is timed around its whole pass,
+/// such as the evaluation of a widget over the `4n` coset, from the thread
+/// driving the proof. Work a pass spreads over the rayon thread pool is
+/// counted once, not summed over the threads.
+#[cfg(feature = "profiling")]
+#[derive(Debug, Clone, Default)]
+pub struct ProvingProfile {
+    /// Time spent computing the quotient contribution of each widget, keyed
+    /// by `"arithmetic"`, `"range"`, `"logic"`, `"fixed_base"`,
+    /// `"variable_base"` and `"permutation"`. The permutation also accounts
+    /// for computing its grand product polynomial.
+    pub per_widget: HashMap<&'static str, Duration>,
+    /// Time spent in FFTs and inverse FFTs outside of the widgets.
+    pub fft: Duration,
+    /// Time spent committing to polynomials.
+    pub msm: Duration,
+}
+
+/// Accumulates the time spent in each [`Stage`] of a proof.
+#[cfg(feature = "profiling")]
+#[derive(Debug, Default)]
+pub(crate) struct Recorder {
+    nanos: [AtomicU64; Stage::COUNT],
+}
+
+#[cfg(feature = "profiling")]
+impl Recorder {
+    /// Calls `f`, adding the time it took to `stage`.
+    pub(crate) fn time<T>(&self, stage: Stage, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let res = f();
+        let elapsed = start.elapsed().as_nanos() as u64;
+        self.nanos[stage as usize].fetch_add(elapsed, Ordering::Relaxed);
+
+        res
+    }
+
+    /// Returns the time recorded so far.
+    pub(crate) fn profile(&self) -> ProvingProfile {
+        let duration = |stage: Stage| {
+            Duration::from_nanos(
+                self.nanos[stage as usize].load(Ordering::Relaxed),
+            )
+        };
+
+        ProvingProfile {
+            per_widget: Stage::WIDGETS
+                .iter()
+                .map(|&(stage, name)| (name, duration(stage)))
+                .collect(),
+            fft: duration(Stage::Fft),
+            msm: duration(Stage::Msm),
+        }
+    }
+}
+
+/// Accumulates the time spent in each [`Stage`] of a proof.
+#[cfg(not(feature = "profiling"))]
+#[derive(Debug, Default)]
+pub(crate) struct Recorder;
+
+#[cfg(not(feature = "profiling"))]
+impl Recorder {
+    /// Calls `f`.
+    #[inline(always)]
+    pub(crate) fn time<T>(&self, _stage: Stage, f: impl FnOnce() -> T) -> T {
+        f()
+    }
+}
+
+#[cfg(feature = "profiling")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commitment_scheme::kzg10::PublicParameters;
+    use crate::constraint_system::helper::dummy_gadget;
+    use crate::error::Error;
+    use crate::proof_system::{Prover, Verifier};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_prove_profiled() -> Result<(), Error> {
+        let public_parameters = PublicParameters::setup(1 << 9, &mut OsRng)?;
+        let (ck, opening_key) = public_parameters.trim(1 << 8)?;
+
+        let mut prover = Prover::new(b"profiling");
+        dummy_gadget(20, prover.mut_cs());
+        let public_inputs = prover.cs.construct_dense_pi_vec();
+        let (proof, profile) = prover.prove_profiled(&ck)?;
+
+        let mut verifier = Verifier::new(b"profiling");
+        dummy_gadget(20, verifier.mut_cs());
+        verifier.preprocess(&ck)?;
+        verifier.verify(&proof, &opening_key, &public_inputs)?;
+
+        assert_eq!(profile.per_widget.len(), Stage::WIDGETS.len());
+        for (_, name) in Stage::WIDGETS.iter() {
+            assert!(profile.per_widget[name] > Duration::from_nanos(0));
+        }
+        assert!(profile.fft > Duration::from_nanos(0));
+        assert!(profile.msm > Duration::from_nanos(0));
+
+        Ok(())
+    }
+}
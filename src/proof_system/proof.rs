@@ -24,6 +24,15 @@ use dusk_bytes::{DeserializableSlice, Serializable};
 /// [`Verifier`](super::Verifier) have in common succintly and without any
 /// capabilities of adquiring any kind of knowledge about the witness used to
 /// construct the Proof.
+///
+/// # Serialization
+///
+/// A serialized [`Proof`] always takes [`PROOF_SIZE`] bytes: the compressed
+/// commitments `a`, `b`, `c`, `d`, `z`, `t_1`, `t_2`, `t_3`, `t_4`, `w_z` and
+/// `w_zw`, 48 bytes each, followed by the evaluations `a`, `b`, `c`, `d`,
+/// `a_next`, `b_next`, `d_next`, `q_arith`, `q_c`, `q_l`, `q_r`,
+/// `left_sigma`, `right_sigma`, `out_sigma`, `lin_poly` and `perm`, 32 bytes
+/// each in little endian.
 #[derive(Debug, Eq, PartialEq, Clone, Default)]
 pub struct Proof {
     /// Commitment to the witness polynomial for the left wires.
@@ -55,6 +64,20 @@ pub struct Proof {
     pub(crate) evaluations: ProofEvaluations,
 }
 
+/// Size in bytes of a serialized [`Proof`], for verifiers that preallocate
+/// their buffers.
+pub const PROOF_SIZE: usize = Proof::SIZE;
+
+/// Returns the exact length in bytes of a serialized [`Proof`], to estimate
+/// fees or allocate buffers before proving.
+///
+/// Every proof holds the same number of commitments and evaluations,
+/// whatever the circuit, since the quotient polynomial is always committed
+/// to in four chunks. The size is therefore [`PROOF_SIZE`].
+pub const fn proof_size_bytes() -> usize {
+    PROOF_SIZE
+}
+
 impl Serializable<{ 11 * Commitment::SIZE + ProofEvaluations::SIZE }>
     for Proof
 {
@@ -128,25 +151,72 @@ pub(crate) mod alloc {
     };
     use ::alloc::vec::Vec;
     use dusk_bls12_381::{
-        multiscalar_mul::msm_variable_base, BlsScalar, G1Affine,
+        multiscalar_mul::msm_variable_base, BlsScalar, G1Affine, G1Projective,
     };
     use merlin::Transcript;
     #[cfg(feature = "std")]
     use rayon::prelude::*;
 
-    impl Proof {
-        /// Performs the verification of a [`Proof`] returning a boolean result.
-        pub(crate) fn verify(
+    /// Challenges drawn from the transcript while verifying a [`Proof`], up
+    /// to the evaluation challenge `z`.
+    ///
+    /// They are returned by [`Verifier::challenges`] and consumed by
+    /// [`VerifierKey::linearization_commitment`].
+    ///
+    /// [`Verifier::challenges`]: crate::proof_system::Verifier::challenges
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub struct Challenges {
+        /// Quotient challenge
+        pub alpha: BlsScalar,
+        /// Permutation challenge
+        pub beta: BlsScalar,
+        /// Permutation challenge
+        pub gamma: BlsScalar,
+        /// Evaluation challenge
+        pub z: BlsScalar,
+        /// Separation challenge of the range gates
+        pub range_sep: BlsScalar,
+        /// Separation challenge of the logic gates
+        pub logic_sep: BlsScalar,
+        /// Separation challenge of the fixed base curve addition gates
+        pub fixed_base_sep: BlsScalar,
+        /// Separation challenge of the variable base curve addition gates
+        pub var_base_sep: BlsScalar,
+    }
+
+    impl VerifierKey {
+        /// Computes the commitment to the linearisation polynomial of
+        /// `proof`, from the selector and sigma commitments of the key, the
+        /// evaluations and permutation commitment of the proof and the
+        /// verification `challenges`.
+        ///
+        /// This is the commitment opened at `z` in the final pairing check of
+        /// the verification.
+        pub fn linearization_commitment(
             &self,
-            verifier_key: &VerifierKey,
-            transcript: &mut Transcript,
-            opening_key: &OpeningKey,
-            pub_inputs: &[BlsScalar],
-        ) -> Result<(), Error> {
-            let domain = EvaluationDomain::new(verifier_key.n)?;
+            proof: &Proof,
+            challenges: &Challenges,
+        ) -> Result<Commitment, Error> {
+            let domain = EvaluationDomain::new(self.n)?;
+            let z_h_eval = domain.evaluate_vanishing_polynomial(&challenges.z);
+            let l1_eval = compute_first_lagrange_evaluation(
+                &domain,
+                &z_h_eval,
+                &challenges.z,
+            );
 
-            // Subgroup checks are done when the proof is deserialised.
+            Ok(proof
+                .compute_linearisation_commitment(challenges, l1_eval, self))
+        }
+    }
 
+    impl Proof {
+        /// Appends the commitments of the [`Proof`] to `transcript`, drawing
+        /// the [`Challenges`] in between, up to the evaluation challenge.
+        pub(crate) fn challenges(
+            &self,
+            transcript: &mut Transcript,
+        ) -> Challenges {
             // In order for the Verifier and Prover to have the same view in the
             // non-interactive setting Both parties must commit the same
             // elements into the transcript Below the verifier will simulate
@@ -169,13 +239,13 @@ pub(crate) mod alloc {
 
             // Compute quotient challenge
             let alpha = transcript.challenge_scalar(b"alpha");
-            let range_sep_challenge =
+            let range_sep =
                 transcript.challenge_scalar(b"range separation challenge");
-            let logic_sep_challenge =
+            let logic_sep =
                 transcript.challenge_scalar(b"logic separation challenge");
-            let fixed_base_sep_challenge =
+            let fixed_base_sep =
                 transcript.challenge_scalar(b"fixed base separation challenge");
-            let var_base_sep_challenge = transcript
+            let var_base_sep = transcript
                 .challenge_scalar(b"variable base separation challenge");
 
             // Add commitment to quotient polynomial to transcript
@@ -185,21 +255,78 @@ pub(crate) mod alloc {
             transcript.append_commitment(b"t_4", &self.t_4_comm);
 
             // Compute evaluation challenge
-            let z_challenge = transcript.challenge_scalar(b"z");
+            let z = transcript.challenge_scalar(b"z");
+
+            Challenges {
+                alpha,
+                beta,
+                gamma,
+                z,
+                range_sep,
+                logic_sep,
+                fixed_base_sep,
+                var_base_sep,
+            }
+        }
+
+        /// Performs the verification of a [`Proof`] returning a boolean result.
+        ///
+        /// `domain` is the evaluation domain of the circuit, of size `n`.
+        pub(crate) fn verify(
+            &self,
+            verifier_key: &VerifierKey,
+            domain: &EvaluationDomain,
+            transcript: &mut Transcript,
+            opening_key: &OpeningKey,
+            pub_inputs: &[BlsScalar],
+        ) -> Result<(), Error> {
+            let (total_c, total_w) = self.verification_terms(
+                verifier_key,
+                domain,
+                transcript,
+                opening_key,
+                pub_inputs,
+            )?;
+
+            opening_key
+                .check_terms(total_c, total_w)
+                .map_err(|_| Error::ProofVerificationError)
+        }
+
+        /// Replays the transcript of the [`Proof`] and folds its openings
+        /// into the two points whose pairing check decides its validity.
+        pub(crate) fn verification_terms(
+            &self,
+            verifier_key: &VerifierKey,
+            domain: &EvaluationDomain,
+            transcript: &mut Transcript,
+            opening_key: &OpeningKey,
+            pub_inputs: &[BlsScalar],
+        ) -> Result<(G1Projective, G1Projective), Error> {
+            // Subgroup checks are done when the proof is deserialised.
+
+            let challenges = self.challenges(transcript);
+            let Challenges {
+                alpha,
+                beta,
+                gamma,
+                z: z_challenge,
+                ..
+            } = challenges;
 
             // Compute zero polynomial evaluated at `z_challenge`
             let z_h_eval = domain.evaluate_vanishing_polynomial(&z_challenge);
 
             // Compute first lagrange polynomial evaluated at `z_challenge`
             let l1_eval = compute_first_lagrange_evaluation(
-                &domain,
+                domain,
                 &z_h_eval,
                 &z_challenge,
             );
 
             // Compute quotient polynomial evaluated at `z_challenge`
             let t_eval = self.compute_quotient_evaluation(
-                &domain,
+                domain,
                 pub_inputs,
                 &alpha,
                 &beta,
@@ -208,6 +335,7 @@ pub(crate) mod alloc {
                 &z_h_eval,
                 &l1_eval,
                 &self.evaluations.perm_eval,
+                verifier_key.permutation_enabled(),
             );
 
             // Compute commitment to quotient polynomial
@@ -251,16 +379,7 @@ pub(crate) mod alloc {
 
             // Compute linearisation commitment
             let r_comm = self.compute_linearisation_commitment(
-                &alpha,
-                &beta,
-                &gamma,
-                (
-                    &range_sep_challenge,
-                    &logic_sep_challenge,
-                    &fixed_base_sep_challenge,
-                    &var_base_sep_challenge,
-                ),
-                &z_challenge,
+                &challenges,
                 l1_eval,
                 &verifier_key,
             );
@@ -317,18 +436,12 @@ pub(crate) mod alloc {
             transcript.append_commitment(b"w_z", &self.w_z_comm);
             transcript.append_commitment(b"w_z_w", &self.w_zw_comm);
 
-            // Batch check
-            if opening_key
-                .batch_check(
-                    &[z_challenge, (z_challenge * domain.group_gen)],
-                    &[flattened_proof_a, flattened_proof_b],
-                    transcript,
-                )
-                .is_err()
-            {
-                return Err(Error::ProofVerificationError);
-            }
-            Ok(())
+            // Batch check terms
+            Ok(opening_key.batch_terms(
+                &[z_challenge, (z_challenge * domain.group_gen)],
+                &[flattened_proof_a, flattened_proof_b],
+                transcript,
+            ))
         }
 
         fn compute_quotient_evaluation(
@@ -342,6 +455,7 @@ pub(crate) mod alloc {
             z_h_eval: &BlsScalar,
             l1_eval: &BlsScalar,
             z_hat_eval: &BlsScalar,
+            permutation_enabled: bool,
         ) -> BlsScalar {
             // Compute the public input polynomial evaluated at `z_challenge`
             let pi_eval =
@@ -351,6 +465,11 @@ pub(crate) mod alloc {
             // r + PI(z)
             let a = self.evaluations.lin_poly_eval + pi_eval;
 
+            // Without the permutation argument t(z) = (r + PI(z)) / Z_H(z)
+            if !permutation_enabled {
+                return a * z_h_eval.invert().unwrap();
+            }
+
             // a + beta * sigma_1 + gamma
             let beta_sig1 = beta * self.evaluations.left_sigma_eval;
             let b_0 = self.evaluations.a_eval + beta_sig1 + gamma;
@@ -394,16 +513,7 @@ pub(crate) mod alloc {
         // Commitment to [r]_1
         fn compute_linearisation_commitment(
             &self,
-            alpha: &BlsScalar,
-            beta: &BlsScalar,
-            gamma: &BlsScalar,
-            (
-                range_sep_challenge,
-                logic_sep_challenge,
-                fixed_base_sep_challenge,
-                var_base_sep_challenge,
-            ): (&BlsScalar, &BlsScalar, &BlsScalar, &BlsScalar),
-            z_challenge: &BlsScalar,
+            challenges: &Challenges,
             l1_eval: BlsScalar,
             verifier_key: &VerifierKey,
         ) -> Commitment {
@@ -417,42 +527,44 @@ pub(crate) mod alloc {
             );
 
             verifier_key.range.compute_linearisation_commitment(
-                &range_sep_challenge,
+                &challenges.range_sep,
                 &mut scalars,
                 &mut points,
                 &self.evaluations,
             );
 
             verifier_key.logic.compute_linearisation_commitment(
-                &logic_sep_challenge,
+                &challenges.logic_sep,
                 &mut scalars,
                 &mut points,
                 &self.evaluations,
             );
 
             verifier_key.fixed_base.compute_linearisation_commitment(
-                &fixed_base_sep_challenge,
+                &challenges.fixed_base_sep,
                 &mut scalars,
                 &mut points,
                 &self.evaluations,
             );
 
             verifier_key.variable_base.compute_linearisation_commitment(
-                &var_base_sep_challenge,
+                &challenges.var_base_sep,
                 &mut scalars,
                 &mut points,
                 &self.evaluations,
             );
 
-            verifier_key.permutation.compute_linearisation_commitment(
-                &mut scalars,
-                &mut points,
-                &self.evaluations,
-                z_challenge,
-                (alpha, beta, gamma),
-                &l1_eval,
-                self.z_comm.0,
-            );
+            if verifier_key.permutation_enabled() {
+                verifier_key.permutation.compute_linearisation_commitment(
+                    &mut scalars,
+                    &mut points,
+                    &self.evaluations,
+                    &challenges.z,
+                    (&challenges.alpha, &challenges.beta, &challenges.gamma),
+                    &l1_eval,
+                    self.z_comm.0,
+                );
+            }
 
             Commitment::from(msm_variable_base(&points, &scalars))
         }
@@ -567,4 +679,68 @@ mod proof_tests {
         let got_proof = Proof::from_bytes(&proof_bytes).unwrap();
         assert_eq!(got_proof, proof);
     }
+
+    #[test]
+    fn test_proof_golden_bytes() {
+        use ::alloc::vec::Vec;
+        use dusk_bls12_381::G1Affine;
+
+        // One hex encoded field per line, each preceded by its label. When
+        // the layout changes on purpose, regenerate the file from the proof
+        // below.
+        let golden: Vec<u8> = include_str!("proof.hex")
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .flat_map(|line| {
+                (0..line.len()).step_by(2).map(move |i| {
+                    u8::from_str_radix(&line[i..i + 2], 16).unwrap()
+                })
+            })
+            .collect();
+
+        let comm = |i: u64| {
+            Commitment::from(G1Affine::generator() * BlsScalar::from(i))
+        };
+        let eval = |i: u64| BlsScalar::from(100 + i);
+        let proof = Proof {
+            a_comm: comm(1),
+            b_comm: comm(2),
+            c_comm: comm(3),
+            d_comm: comm(4),
+            z_comm: comm(5),
+            t_1_comm: comm(6),
+            t_2_comm: comm(7),
+            t_3_comm: comm(8),
+            t_4_comm: comm(9),
+            w_z_comm: comm(10),
+            w_zw_comm: comm(11),
+            evaluations: ProofEvaluations {
+                a_eval: eval(1),
+                b_eval: eval(2),
+                c_eval: eval(3),
+                d_eval: eval(4),
+                a_next_eval: eval(5),
+                b_next_eval: eval(6),
+                d_next_eval: eval(7),
+                q_arith_eval: eval(8),
+                q_c_eval: eval(9),
+                q_l_eval: eval(10),
+                q_r_eval: eval(11),
+                left_sigma_eval: eval(12),
+                right_sigma_eval: eval(13),
+                out_sigma_eval: eval(14),
+                lin_poly_eval: eval(15),
+                perm_eval: eval(16),
+            },
+        };
+
+        assert_eq!(PROOF_SIZE, 11 * 48 + 16 * 32);
+        assert_eq!(golden.len(), PROOF_SIZE);
+        assert_eq!(proof_size_bytes(), PROOF_SIZE);
+        assert_eq!(&proof.to_bytes()[..], &golden[..]);
+
+        let mut bytes = [0u8; PROOF_SIZE];
+        bytes.copy_from_slice(&golden);
+        assert_eq!(Proof::from_bytes(&bytes).unwrap(), proof);
+    }
 }
@@ -4,18 +4,23 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+#[cfg(feature = "profiling")]
+use crate::proof_system::ProvingProfile;
 use crate::{
-    commitment_scheme::kzg10::CommitKey,
+    commitment_scheme::kzg10::{CommitKey, Commitment},
     constraint_system::{StandardComposer, Variable},
     error::Error,
     fft::{EvaluationDomain, Polynomial},
     proof_system::{
-        linearisation_poly, proof::Proof, quotient_poly, ProverKey,
+        linearisation_poly,
+        profiling::{Recorder, Stage},
+        proof::Proof,
+        quotient_poly, ProverKey,
     },
     transcript::TranscriptProtocol,
 };
 use alloc::vec::Vec;
-use dusk_bls12_381::BlsScalar;
+use dusk_bls12_381::{multiscalar_mul::msm_variable_base, BlsScalar};
 use merlin::Transcript;
 
 /// Abstraction structure designed to construct a circuit and generate
@@ -48,6 +53,32 @@ impl Prover {
         self.prover_key = Some(pk);
         Ok(())
     }
+
+    /// Preprocesses the underlying constraint system like
+    /// [`Prover::preprocess`], but seeds the transcript with the `digest` of
+    /// the circuit [`VerifierKey`](super::widget::VerifierKey) instead of
+    /// its commitments.
+    ///
+    /// The [`Verifier`](super::Verifier) must be preprocessed with
+    /// `Verifier::preprocess_with_digest` and the same digest. Returns
+    /// [`Error::MismatchedVerifierKeyDigest`] if `digest` is not the one of
+    /// the circuit.
+    pub fn preprocess_with_digest(
+        &mut self,
+        commit_key: &CommitKey,
+        digest: &[u8; 32],
+    ) -> Result<(), Error> {
+        if self.prover_key.is_some() {
+            return Err(Error::CircuitAlreadyPreprocessed);
+        }
+        let pk = self.cs.preprocess_prover_with_digest(
+            commit_key,
+            &mut self.preprocessed_transcript,
+            digest,
+        )?;
+        self.prover_key = Some(pk);
+        Ok(())
+    }
 }
 
 impl Default for Prover {
@@ -87,11 +118,17 @@ impl Prover {
         n: usize,
         t_x: &Polynomial,
     ) -> (Polynomial, Polynomial, Polynomial, Polynomial) {
+        // Missing coefficients of a lower degree `t(X)` leave the last chunks
+        // empty
+        let chunk = |start: usize, end: usize| {
+            let coeffs = t_x.get(start..end.min(t_x.len())).unwrap_or(&[]);
+            Polynomial::from_coefficients_vec(coeffs.to_vec())
+        };
         (
-            Polynomial::from_coefficients_vec(t_x[0..n].to_vec()),
-            Polynomial::from_coefficients_vec(t_x[n..2 * n].to_vec()),
-            Polynomial::from_coefficients_vec(t_x[2 * n..3 * n].to_vec()),
-            Polynomial::from_coefficients_vec(t_x[3 * n..].to_vec()),
+            chunk(0, n),
+            chunk(n, 2 * n),
+            chunk(2 * n, 3 * n),
+            chunk(3 * n, t_x.len()),
         )
     }
 
@@ -144,6 +181,18 @@ impl Prover {
         self.preprocessed_transcript.append_message(label, message);
     }
 
+    /// Binds external context, such as a session identifier, into the
+    /// [`Transcript`], so that proofs are only valid within that context.
+    ///
+    /// Unlike [`Prover::key_transcript`], `ctx` is length-prefixed, so
+    /// different splits of the same bytes between `label` and `ctx` never
+    /// collide. It must be called before preprocessing, on both the prover
+    /// and the verifier, so that the context precedes the circuit
+    /// commitments.
+    pub fn append_context(&mut self, label: &'static [u8], ctx: &[u8]) {
+        self.preprocessed_transcript.append_context(label, ctx);
+    }
+
     /// Creates a [`Proof]` that demonstrates that a circuit is satisfied.
     /// # Note
     /// If you intend to construct multiple [`Proof`]s with different witnesses,
@@ -155,7 +204,45 @@ impl Prover {
         commit_key: &CommitKey,
         prover_key: &ProverKey,
     ) -> Result<Proof, Error> {
+        self.prove_recorded(commit_key, prover_key, &Recorder::default())
+            .map(|(proof, _)| proof)
+    }
+
+    /// Creates a [`Proof`] like [`Prover::prove_with_preprocessed`], also
+    /// returning the quotient polynomial split into the four chunks that the
+    /// proof commits to.
+    ///
+    /// With `n` the size of the circuit domain, the quotient is
+    /// `t(X) = t_1(X) + X^n t_2(X) + X^2n t_3(X) + X^3n t_4(X)`, where the
+    /// first three chunks have `n` coefficients. The chunks are the ones
+    /// computed for the proof, so they can be committed to with another
+    /// scheme for comparison at no extra proving cost.
+    pub fn prove_with_quotient_chunks(
+        &self,
+        commit_key: &CommitKey,
+        prover_key: &ProverKey,
+    ) -> Result<(Proof, [Polynomial; 4]), Error> {
+        self.prove_recorded(commit_key, prover_key, &Recorder::default())
+    }
+
+    /// Creates a [`Proof`] like [`Prover::prove_with_preprocessed`], timing
+    /// its stages with `recorder` and returning the quotient chunks along
+    /// with it.
+    fn prove_recorded(
+        &self,
+        commit_key: &CommitKey,
+        prover_key: &ProverKey,
+        recorder: &Recorder,
+    ) -> Result<(Proof, [Polynomial; 4]), Error> {
         let domain = EvaluationDomain::new(self.cs.circuit_size())?;
+        let ifft = |evals: &[BlsScalar]| {
+            Polynomial::from_coefficients_vec(
+                recorder.time(Stage::Fft, || domain.ifft(evals)),
+            )
+        };
+        let commit = |poly: &Polynomial| {
+            recorder.time(Stage::Msm, || commit_key.commit(poly))
+        };
 
         // Since the caller is passing a pre-processed circuit
         // We assume that the Transcript has been seeded with the preprocessed
@@ -174,20 +261,16 @@ impl Prover {
 
         // Witnesses are now in evaluation form, convert them to coefficients
         // So that we may commit to them
-        let w_l_poly =
-            Polynomial::from_coefficients_vec(domain.ifft(w_l_scalar));
-        let w_r_poly =
-            Polynomial::from_coefficients_vec(domain.ifft(w_r_scalar));
-        let w_o_poly =
-            Polynomial::from_coefficients_vec(domain.ifft(w_o_scalar));
-        let w_4_poly =
-            Polynomial::from_coefficients_vec(domain.ifft(w_4_scalar));
+        let w_l_poly = ifft(w_l_scalar);
+        let w_r_poly = ifft(w_r_scalar);
+        let w_o_poly = ifft(w_o_scalar);
+        let w_4_poly = ifft(w_4_scalar);
 
         // Commit to witness polynomials
-        let w_l_poly_commit = commit_key.commit(&w_l_poly)?;
-        let w_r_poly_commit = commit_key.commit(&w_r_poly)?;
-        let w_o_poly_commit = commit_key.commit(&w_o_poly)?;
-        let w_4_poly_commit = commit_key.commit(&w_4_poly)?;
+        let w_l_poly_commit = commit(&w_l_poly)?;
+        let w_r_poly_commit = commit(&w_r_poly)?;
+        let w_o_poly_commit = commit(&w_o_poly)?;
+        let w_4_poly_commit = commit(&w_4_poly)?;
 
         // Add witness polynomial commitments to transcript
         transcript.append_commitment(b"w_l", &w_l_poly_commit);
@@ -203,32 +286,32 @@ impl Prover {
         transcript.append_scalar(b"beta", &beta);
         let gamma = transcript.challenge_scalar(b"gamma");
 
-        let z_poly = Polynomial::from_coefficients_slice(
-            &self.cs.perm.compute_permutation_poly(
-                &domain,
-                (&w_l_scalar, &w_r_scalar, &w_o_scalar, &w_4_scalar),
-                &beta,
-                &gamma,
-                (
-                    &prover_key.permutation.left_sigma.0,
-                    &prover_key.permutation.right_sigma.0,
-                    &prover_key.permutation.out_sigma.0,
-                    &prover_key.permutation.fourth_sigma.0,
+        let z_poly = recorder.time(Stage::Permutation, || {
+            Polynomial::from_coefficients_slice(
+                &self.cs.perm.compute_permutation_poly(
+                    &domain,
+                    (&w_l_scalar, &w_r_scalar, &w_o_scalar, &w_4_scalar),
+                    &beta,
+                    &gamma,
+                    (
+                        &prover_key.permutation.left_sigma.0,
+                        &prover_key.permutation.right_sigma.0,
+                        &prover_key.permutation.out_sigma.0,
+                        &prover_key.permutation.fourth_sigma.0,
+                    ),
                 ),
-            ),
-        );
+            )
+        });
 
         // Commit to permutation polynomial
         //
-        let z_poly_commit = commit_key.commit(&z_poly)?;
+        let z_poly_commit = commit(&z_poly)?;
 
         // Add permutation polynomial commitment to transcript
         transcript.append_commitment(b"z", &z_poly_commit);
 
         // 3. Compute public inputs polynomial
-        let pi_poly = Polynomial::from_coefficients_vec(
-            domain.ifft(&self.cs.construct_dense_pi_vec()),
-        );
+        let pi_poly = ifft(&self.cs.construct_dense_pi_vec());
 
         // 4. Compute quotient polynomial
         //
@@ -258,17 +341,32 @@ impl Prover {
                 fixed_base_sep_challenge,
                 var_base_sep_challenge,
             ),
+            recorder,
         )?;
 
         // Split quotient polynomial into 4 degree `n` polynomials
         let (t_1_poly, t_2_poly, t_3_poly, t_4_poly) =
             self.split_tx_poly(domain.size(), &t_poly);
 
+        // Without the permutation argument the quotient has a lower degree,
+        // so its last chunks may be constant, which the commit key refuses
+        let commit_chunk = |poly: &Polynomial| match commit(poly) {
+            Err(Error::PolynomialDegreeIsZero)
+                if !prover_key.permutation_enabled() =>
+            {
+                Ok(Commitment::from(msm_variable_base(
+                    &commit_key.powers_of_g,
+                    poly,
+                )))
+            }
+            commitment => commitment,
+        };
+
         // Commit to splitted quotient polynomial
-        let t_1_commit = commit_key.commit(&t_1_poly)?;
-        let t_2_commit = commit_key.commit(&t_2_poly)?;
-        let t_3_commit = commit_key.commit(&t_3_poly)?;
-        let t_4_commit = commit_key.commit(&t_4_poly)?;
+        let t_1_commit = commit_chunk(&t_1_poly)?;
+        let t_2_commit = commit_chunk(&t_2_poly)?;
+        let t_3_commit = commit_chunk(&t_3_poly)?;
+        let t_4_commit = commit_chunk(&t_4_poly)?;
 
         // Add quotient polynomial commitments to transcript
         transcript.append_commitment(b"t_1", &t_1_commit);
@@ -362,7 +460,7 @@ impl Prover {
             &z_challenge,
             &mut transcript,
         );
-        let w_z_comm = commit_key.commit(&aggregate_witness)?;
+        let w_z_comm = commit(&aggregate_witness)?;
 
         // Compute aggregate witness to polynomials evaluated at the shifted
         // evaluation challenge
@@ -371,10 +469,10 @@ impl Prover {
             &(z_challenge * domain.group_gen),
             &mut transcript,
         );
-        let w_zx_comm = commit_key.commit(&shifted_aggregate_witness)?;
+        let w_zx_comm = commit(&shifted_aggregate_witness)?;
 
         // Create Proof
-        Ok(Proof {
+        let proof = Proof {
             a_comm: w_l_poly_commit,
             b_comm: w_r_poly_commit,
             c_comm: w_o_poly_commit,
@@ -391,13 +489,36 @@ impl Prover {
             w_zw_comm: w_zx_comm,
 
             evaluations: evaluations.proof,
-        })
+        };
+
+        Ok((proof, [t_1_poly, t_2_poly, t_3_poly, t_4_poly]))
     }
 
     /// Proves a circuit is satisfied, then clears the witness variables
     /// If the circuit is not pre-processed, then the preprocessed circuit will
     /// also be computed.
     pub fn prove(&mut self, commit_key: &CommitKey) -> Result<Proof, Error> {
+        self.prove_and_clear(commit_key, &Recorder::default())
+    }
+
+    /// Proves a circuit is satisfied like [`Prover::prove`], also returning
+    /// the time spent in each proving stage.
+    #[cfg(feature = "profiling")]
+    pub fn prove_profiled(
+        &mut self,
+        commit_key: &CommitKey,
+    ) -> Result<(Proof, ProvingProfile), Error> {
+        let recorder = Recorder::default();
+        let proof = self.prove_and_clear(commit_key, &recorder)?;
+
+        Ok((proof, recorder.profile()))
+    }
+
+    fn prove_and_clear(
+        &mut self,
+        commit_key: &CommitKey,
+        recorder: &Recorder,
+    ) -> Result<Proof, Error> {
         let prover_key: &ProverKey;
 
         if self.prover_key.is_none() {
@@ -412,7 +533,8 @@ impl Prover {
 
         prover_key = self.prover_key.as_ref().unwrap();
 
-        let proof = self.prove_with_preprocessed(commit_key, prover_key)?;
+        let (proof, _) =
+            self.prove_recorded(commit_key, prover_key, recorder)?;
 
         // Clear witness and reset composer variables
         self.clear_witness();
@@ -420,3 +542,175 @@ impl Prover {
         Ok(proof)
     }
 }
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commitment_scheme::kzg10::PublicParameters;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_required_srs_degree() {
+        use crate::constraint_system::helper::dummy_gadget;
+
+        let public_parameters =
+            PublicParameters::setup(1 << 9, &mut OsRng).unwrap();
+        let (commit_key, _) = public_parameters.trim(1 << 8).unwrap();
+
+        let mut prover = Prover::new(b"test");
+        dummy_gadget(100, prover.mut_cs());
+        prover.preprocess(&commit_key).unwrap();
+        let degree = prover.prover_key.as_ref().unwrap().required_srs_degree();
+        assert_eq!(degree, prover.prover_key.as_ref().unwrap().n - 1);
+
+        let (commit_key, _) = public_parameters.trim(degree).unwrap();
+        assert!(prover.prove(&commit_key).is_ok());
+
+        let (commit_key, _) = public_parameters.trim(degree - 1).unwrap();
+        dummy_gadget(100, prover.mut_cs());
+        assert!(matches!(
+            prover.prove(&commit_key),
+            Err(Error::PolynomialDegreeTooLarge { max, .. }) if max == degree - 1
+        ));
+    }
+
+    #[test]
+    fn test_prove_with_quotient_chunks() {
+        use crate::constraint_system::helper::dummy_gadget;
+
+        let public_parameters =
+            PublicParameters::setup(1 << 9, &mut OsRng).unwrap();
+        let (commit_key, _) = public_parameters.trim(1 << 8).unwrap();
+
+        let mut prover = Prover::new(b"test");
+        dummy_gadget(100, prover.mut_cs());
+        prover.preprocess(&commit_key).unwrap();
+        let prover_key = prover.prover_key.as_ref().unwrap();
+        let (proof, chunks) = prover
+            .prove_with_quotient_chunks(&commit_key, prover_key)
+            .unwrap();
+
+        // The chunks are the ones committed to in the proof
+        let commitments = [
+            proof.t_1_comm,
+            proof.t_2_comm,
+            proof.t_3_comm,
+            proof.t_4_comm,
+        ];
+        for (chunk, commitment) in chunks.iter().zip(commitments.iter()) {
+            assert_eq!(&commit_key.commit(chunk).unwrap(), commitment);
+        }
+
+        // Chunks recombine into a quotient of degree lower than 4n
+        let n = prover_key.n.next_power_of_two();
+        assert!(chunks[..3].iter().all(|chunk| chunk.len() == n));
+        assert!(chunks[3].len() <= n);
+
+        assert_eq!(
+            proof,
+            prover
+                .prove_with_preprocessed(&commit_key, prover_key)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_prove_unsatisfied_circuit() {
+        let public_parameters =
+            PublicParameters::setup(1 << 9, &mut OsRng).unwrap();
+        let (commit_key, _) = public_parameters.trim(1 << 8).unwrap();
+
+        let mut prover = Prover::new(b"test");
+        let composer = prover.mut_cs();
+        let a = composer.add_input(BlsScalar::from(3));
+        composer.constrain_to_constant(a, BlsScalar::from(4), None);
+
+        assert!(matches!(
+            prover.prove(&commit_key),
+            Err(Error::QuotientNotDivisible { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "debug-unsound")]
+    fn test_prove_with_permutation_disabled() -> Result<(), Error> {
+        use crate::proof_system::Verifier;
+
+        // x + y - 2 * z = 0, where the keys make the three wires copies of a
+        // single variable
+        let gadget = |composer: &mut StandardComposer, values: [u64; 3]| {
+            let [x, y, z] = values;
+            let x = composer.add_input(BlsScalar::from(x));
+            let (y, z) = if values == [values[0]; 3] {
+                (x, x)
+            } else {
+                let y = composer.add_input(BlsScalar::from(y));
+                (y, composer.add_input(BlsScalar::from(z)))
+            };
+            composer.poly_gate(
+                x,
+                y,
+                z,
+                BlsScalar::zero(),
+                BlsScalar::one(),
+                BlsScalar::one(),
+                -BlsScalar::from(2),
+                BlsScalar::zero(),
+                None,
+            );
+            composer.add_dummy_constraints();
+            composer.add_dummy_constraints();
+        };
+
+        let (commit_key, opening_key) = CommitKey::setup_for_tests(1 << 5, 0)?;
+        let mut prover = Prover::new(b"unsound");
+        gadget(prover.mut_cs(), [1, 1, 1]);
+        prover.preprocess(&commit_key)?;
+        prover.clear_witness();
+        let preprocessed_verifier = || -> Result<Verifier, Error> {
+            let mut verifier = Verifier::new(b"unsound");
+            gadget(verifier.mut_cs(), [1, 1, 1]);
+            verifier.preprocess(&commit_key)?;
+            Ok(verifier)
+        };
+        let mut verifier = preprocessed_verifier()?;
+        let strict_verifier = preprocessed_verifier()?;
+
+        // The gate holds, but the copy constraints do not
+        gadget(prover.mut_cs(), [1, 3, 2]);
+        let public_inputs = prover.cs.construct_dense_pi_vec();
+        assert!(matches!(
+            prover.prove(&commit_key),
+            Err(Error::QuotientNotDivisible { .. })
+        ));
+        prover.clear_witness();
+
+        prover
+            .prover_key
+            .as_mut()
+            .unwrap()
+            .disable_permutation_unsound();
+        verifier
+            .verifier_key
+            .as_mut()
+            .unwrap()
+            .disable_permutation_unsound();
+        gadget(prover.mut_cs(), [1, 3, 2]);
+        let proof = prover.prove(&commit_key)?;
+        verifier.verify(&proof, &opening_key, &public_inputs)?;
+
+        // A verifier whose key still has the permutation enabled rejects it
+        assert!(strict_verifier
+            .verify(&proof, &opening_key, &public_inputs)
+            .is_err());
+
+        // The gate itself is still checked
+        gadget(prover.mut_cs(), [1, 3, 1]);
+        assert!(matches!(
+            prover.prove(&commit_key),
+            Err(Error::QuotientNotDivisible { .. })
+        ));
+        Ok(())
+    }
+}
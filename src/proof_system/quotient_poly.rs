@@ -7,6 +7,7 @@
 use crate::{
     error::Error,
     fft::{EvaluationDomain, Polynomial},
+    proof_system::profiling::{Recorder, Stage},
     proof_system::ProverKey,
 };
 use alloc::vec::Vec;
@@ -44,29 +45,33 @@ pub(crate) fn compute(
         BlsScalar,
         BlsScalar,
     ),
+    recorder: &Recorder,
 ) -> Result<Polynomial, Error> {
     // Compute 4n eval of z(X)
     let domain_4n = EvaluationDomain::new(4 * domain.size())?;
-    let mut z_eval_4n = domain_4n.coset_fft(&z_poly);
+    let coset_fft = |coeffs: &[BlsScalar]| {
+        recorder.time(Stage::Fft, || domain_4n.coset_fft(coeffs))
+    };
+    let mut z_eval_4n = coset_fft(&z_poly);
     z_eval_4n.push(z_eval_4n[0]);
     z_eval_4n.push(z_eval_4n[1]);
     z_eval_4n.push(z_eval_4n[2]);
     z_eval_4n.push(z_eval_4n[3]);
 
     // Compute 4n evaluations of the wire polynomials
-    let mut wl_eval_4n = domain_4n.coset_fft(&w_l_poly);
+    let mut wl_eval_4n = coset_fft(&w_l_poly);
     wl_eval_4n.push(wl_eval_4n[0]);
     wl_eval_4n.push(wl_eval_4n[1]);
     wl_eval_4n.push(wl_eval_4n[2]);
     wl_eval_4n.push(wl_eval_4n[3]);
-    let mut wr_eval_4n = domain_4n.coset_fft(&w_r_poly);
+    let mut wr_eval_4n = coset_fft(&w_r_poly);
     wr_eval_4n.push(wr_eval_4n[0]);
     wr_eval_4n.push(wr_eval_4n[1]);
     wr_eval_4n.push(wr_eval_4n[2]);
     wr_eval_4n.push(wr_eval_4n[3]);
-    let wo_eval_4n = domain_4n.coset_fft(&w_o_poly);
+    let wo_eval_4n = coset_fft(&w_o_poly);
 
-    let mut w4_eval_4n = domain_4n.coset_fft(&w_4_poly);
+    let mut w4_eval_4n = coset_fft(&w_4_poly);
     w4_eval_4n.push(w4_eval_4n[0]);
     w4_eval_4n.push(w4_eval_4n[1]);
     w4_eval_4n.push(w4_eval_4n[2]);
@@ -83,15 +88,21 @@ pub(crate) fn compute(
         prover_key,
         (&wl_eval_4n, &wr_eval_4n, &wo_eval_4n, &w4_eval_4n),
         public_inputs_poly,
+        recorder,
     );
 
-    let t_2 = compute_permutation_checks(
-        domain,
-        prover_key,
-        (&wl_eval_4n, &wr_eval_4n, &wo_eval_4n, &w4_eval_4n),
-        &z_eval_4n,
-        (alpha, beta, gamma),
-    );
+    let t_2 = if prover_key.permutation_enabled() {
+        compute_permutation_checks(
+            domain,
+            prover_key,
+            (&wl_eval_4n, &wr_eval_4n, &wo_eval_4n, &w4_eval_4n),
+            &z_eval_4n,
+            (alpha, beta, gamma),
+            recorder,
+        )
+    } else {
+        vec![BlsScalar::zero(); domain_4n.size()]
+    };
 
     #[cfg(not(feature = "std"))]
     let range = (0..domain_4n.size()).into_iter();
@@ -107,9 +118,23 @@ pub(crate) fn compute(
         })
         .collect();
 
-    Ok(Polynomial::from_coefficients_vec(
-        domain_4n.coset_ifft(&quotient),
-    ))
+    let t_poly = Polynomial::from_coefficients_vec(
+        recorder.time(Stage::Fft, || domain_4n.coset_ifft(&quotient)),
+    );
+
+    // The numerator has a degree of at most `5n - 5`, so dividing it exactly
+    // by the vanishing polynomial leaves a quotient of degree at most
+    // `4n - 5`. Otherwise the interpolation over the `4n` coset is not the
+    // quotient at all, and the proof is bound to be rejected. Checking the
+    // degree is a single scan of the coefficients, so it is done in every
+    // build.
+    if t_poly.degree() + 5 > domain_4n.size() {
+        return Err(Error::QuotientNotDivisible {
+            degree: t_poly.degree(),
+        });
+    }
+
+    Ok(t_poly)
 }
 
 // Ensures that the circuit is satisfied
@@ -129,81 +154,110 @@ fn compute_circuit_satisfiability_equation(
         &[BlsScalar],
     ),
     pi_poly: &Polynomial,
+    recorder: &Recorder,
 ) -> Vec<BlsScalar> {
     let domain_4n = EvaluationDomain::new(4 * domain.size()).unwrap();
-    let pi_eval_4n = domain_4n.coset_fft(pi_poly);
+    let mut t = recorder.time(Stage::Fft, || domain_4n.coset_fft(pi_poly));
 
-    #[cfg(not(feature = "std"))]
-    let range = (0..domain_4n.size()).into_iter();
+    let arithmetic = |i: usize| {
+        prover_key.arithmetic.compute_quotient_i(
+            i,
+            &wl_eval_4n[i],
+            &wr_eval_4n[i],
+            &wo_eval_4n[i],
+            &w4_eval_4n[i],
+        )
+    };
 
-    #[cfg(feature = "std")]
-    let range = (0..domain_4n.size()).into_par_iter();
-
-    let t: Vec<_> = range
-        .map(|i| {
-            let wl = &wl_eval_4n[i];
-            let wr = &wr_eval_4n[i];
-            let wo = &wo_eval_4n[i];
-            let w4 = &w4_eval_4n[i];
-            let wl_next = &wl_eval_4n[i + 4];
-            let wr_next = &wr_eval_4n[i + 4];
-            let w4_next = &w4_eval_4n[i + 4];
-            let pi = &pi_eval_4n[i];
+    let range = |i: usize| {
+        prover_key.range.compute_quotient_i(
+            i,
+            range_challenge,
+            &wl_eval_4n[i],
+            &wr_eval_4n[i],
+            &wo_eval_4n[i],
+            &w4_eval_4n[i],
+            &w4_eval_4n[i + 4],
+        )
+    };
 
-            let a = prover_key.arithmetic.compute_quotient_i(i, wl, wr, wo, w4);
+    let logic = |i: usize| {
+        prover_key.logic.compute_quotient_i(
+            i,
+            logic_challenge,
+            &wl_eval_4n[i],
+            &wl_eval_4n[i + 4],
+            &wr_eval_4n[i],
+            &wr_eval_4n[i + 4],
+            &wo_eval_4n[i],
+            &w4_eval_4n[i],
+            &w4_eval_4n[i + 4],
+        )
+    };
 
-            let b = prover_key.range.compute_quotient_i(
-                i,
-                range_challenge,
-                wl,
-                wr,
-                wo,
-                w4,
-                w4_next,
-            );
+    let fixed_base = |i: usize| {
+        prover_key.fixed_base.compute_quotient_i(
+            i,
+            fixed_base_challenge,
+            &wl_eval_4n[i],
+            &wl_eval_4n[i + 4],
+            &wr_eval_4n[i],
+            &wr_eval_4n[i + 4],
+            &wo_eval_4n[i],
+            &w4_eval_4n[i],
+            &w4_eval_4n[i + 4],
+        )
+    };
 
-            let c = prover_key.logic.compute_quotient_i(
-                i,
-                logic_challenge,
-                &wl,
-                &wl_next,
-                &wr,
-                &wr_next,
-                &wo,
-                &w4,
-                &w4_next,
-            );
+    let variable_base = |i: usize| {
+        prover_key.variable_base.compute_quotient_i(
+            i,
+            var_base_challenge,
+            &wl_eval_4n[i],
+            &wl_eval_4n[i + 4],
+            &wr_eval_4n[i],
+            &wr_eval_4n[i + 4],
+            &wo_eval_4n[i],
+            &w4_eval_4n[i],
+            &w4_eval_4n[i + 4],
+        )
+    };
 
-            let d = prover_key.fixed_base.compute_quotient_i(
-                i,
-                fixed_base_challenge,
-                &wl,
-                &wl_next,
-                &wr,
-                &wr_next,
-                &wo,
-                &w4,
-                &w4_next,
-            );
+    // All the widgets are evaluated in a single pass over the domain.
+    #[cfg(not(feature = "profiling"))]
+    add_to_each(&mut t, |i| {
+        arithmetic(i) + range(i) + logic(i) + fixed_base(i) + variable_base(i)
+    });
 
-            let e = prover_key.variable_base.compute_quotient_i(
-                i,
-                var_base_challenge,
-                &wl,
-                &wl_next,
-                &wr,
-                &wr_next,
-                &wo,
-                &w4,
-                &w4_next,
-            );
+    // Each widget is evaluated over the whole domain in its own pass, so
+    // that its time is recorded once per proof rather than once per row.
+    #[cfg(feature = "profiling")]
+    {
+        recorder.time(Stage::Arithmetic, || add_to_each(&mut t, arithmetic));
+        recorder.time(Stage::Range, || add_to_each(&mut t, range));
+        recorder.time(Stage::Logic, || add_to_each(&mut t, logic));
+        recorder.time(Stage::FixedBase, || add_to_each(&mut t, fixed_base));
+        recorder
+            .time(Stage::VariableBase, || add_to_each(&mut t, variable_base));
+    }
 
-            (a + pi) + b + c + d + e
-        })
-        .collect();
     t
 }
 
+/// Adds `f(i)` to the `i`-th element of `t`, for every element.
+fn add_to_each<F>(t: &mut [BlsScalar], f: F)
+where
+    F: Fn(usize) -> BlsScalar + Sync + Send,
+{
+    #[cfg(not(feature = "std"))]
+    let iter = t.iter_mut().enumerate();
+
+    #[cfg(feature = "std")]
+    let iter = t.par_iter_mut().enumerate();
+
+    iter.for_each(|(i, t)| *t += f(i));
+}
+
 fn compute_permutation_checks(
     domain: &EvaluationDomain,
     prover_key: &ProverKey,
@@ -215,11 +269,13 @@ fn compute_permutation_checks(
     ),
     z_eval_4n: &[BlsScalar],
     (alpha, beta, gamma): (&BlsScalar, &BlsScalar, &BlsScalar),
+    recorder: &Recorder,
 ) -> Vec<BlsScalar> {
     let domain_4n = EvaluationDomain::new(4 * domain.size()).unwrap();
     let l1_poly_alpha =
         compute_first_lagrange_poly_scaled(domain, alpha.square());
-    let l1_alpha_sq_evals = domain_4n.coset_fft(&l1_poly_alpha.coeffs);
+    let l1_alpha_sq_evals = recorder
+        .time(Stage::Fft, || domain_4n.coset_fft(&l1_poly_alpha.coeffs));
 
     #[cfg(not(feature = "std"))]
     let range = (0..domain_4n.size()).into_iter();
@@ -227,25 +283,27 @@ fn compute_permutation_checks(
     #[cfg(feature = "std")]
     let range = (0..domain_4n.size()).into_par_iter();
 
-    let t: Vec<_> = range
-        .map(|i| {
-            prover_key.permutation.compute_quotient_i(
-                i,
-                &wl_eval_4n[i],
-                &wr_eval_4n[i],
-                &wo_eval_4n[i],
-                &w4_eval_4n[i],
-                &z_eval_4n[i],
-                &z_eval_4n[i + 4],
-                &alpha,
-                &l1_alpha_sq_evals[i],
-                &beta,
-                &gamma,
-            )
-        })
-        .collect();
-    t
+    recorder.time(Stage::Permutation, || {
+        range
+            .map(|i| {
+                prover_key.permutation.compute_quotient_i(
+                    i,
+                    &wl_eval_4n[i],
+                    &wr_eval_4n[i],
+                    &wo_eval_4n[i],
+                    &w4_eval_4n[i],
+                    &z_eval_4n[i],
+                    &z_eval_4n[i + 4],
+                    &alpha,
+                    &l1_alpha_sq_evals[i],
+                    &beta,
+                    &gamma,
+                )
+            })
+            .collect()
+    })
 }
+
 fn compute_first_lagrange_poly_scaled(
     domain: &EvaluationDomain,
     scale: BlsScalar,
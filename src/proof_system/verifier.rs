@@ -7,9 +7,12 @@
 use crate::commitment_scheme::kzg10::{CommitKey, OpeningKey};
 use crate::constraint_system::StandardComposer;
 use crate::error::Error;
+use crate::fft::EvaluationDomain;
 use crate::proof_system::widget::VerifierKey;
-use crate::proof_system::Proof;
-use dusk_bls12_381::BlsScalar;
+use crate::proof_system::{Challenges, Proof};
+use crate::transcript::TranscriptProtocol;
+use alloc::vec::Vec;
+use dusk_bls12_381::{BlsScalar, G1Projective};
 use merlin::Transcript;
 
 /// Abstraction structure designed verify [`Proof`]s.
@@ -75,12 +78,47 @@ impl Verifier {
         Ok(())
     }
 
+    /// Preprocesses the circuit like [`Verifier::preprocess`], but seeds the
+    /// transcript with the `digest` of the [`VerifierKey`] instead of its
+    /// commitments.
+    ///
+    /// The [`Prover`](super::Prover) must be preprocessed with
+    /// `Prover::preprocess_with_digest` and the same digest. Returns
+    /// [`Error::MismatchedVerifierKeyDigest`] if `digest` is not the one of
+    /// the circuit.
+    pub fn preprocess_with_digest(
+        &mut self,
+        commit_key: &CommitKey,
+        digest: &[u8; 32],
+    ) -> Result<(), Error> {
+        let vk = self.cs.preprocess_verifier_with_digest(
+            commit_key,
+            &mut self.preprocessed_transcript,
+            digest,
+        )?;
+
+        self.verifier_key = Some(vk);
+        Ok(())
+    }
+
     /// Keys the [`Transcript`] with additional seed information
     /// Wrapper around [`Transcript::append_message`].
     pub fn key_transcript(&mut self, label: &'static [u8], message: &[u8]) {
         self.preprocessed_transcript.append_message(label, message);
     }
 
+    /// Binds external context, such as a session identifier, into the
+    /// [`Transcript`], so that proofs are only valid within that context.
+    ///
+    /// Unlike [`Verifier::key_transcript`], `ctx` is length-prefixed, so
+    /// different splits of the same bytes between `label` and `ctx` never
+    /// collide. It must be called before preprocessing, on both the prover
+    /// and the verifier, so that the context precedes the circuit
+    /// commitments.
+    pub fn append_context(&mut self, label: &'static [u8], ctx: &[u8]) {
+        self.preprocessed_transcript.append_context(label, ctx);
+    }
+
     /// Verifies a [`Proof`].
     pub fn verify(
         &self,
@@ -90,12 +128,462 @@ impl Verifier {
     ) -> Result<(), Error> {
         let mut cloned_transcript = self.preprocessed_transcript.clone();
         let verifier_key = self.verifier_key.as_ref().unwrap();
+        let domain = EvaluationDomain::new(verifier_key.n)?;
 
         proof.verify(
             verifier_key,
+            &domain,
             &mut cloned_transcript,
             opening_key,
             public_inputs,
         )
     }
+
+    /// Replays the preprocessed transcript with the commitments of `proof`,
+    /// returning the [`Challenges`] its verification draws up to the
+    /// evaluation challenge.
+    ///
+    /// Together with [`VerifierKey::linearization_commitment`] this yields
+    /// the intermediate linearisation commitment of the verification.
+    pub fn challenges(&self, proof: &Proof) -> Challenges {
+        proof.challenges(&mut self.preprocessed_transcript.clone())
+    }
+
+    /// Creates a [`VerifierContext`] for the circuit of this `Verifier`,
+    /// starting from its preprocessed transcript.
+    ///
+    /// # Panics
+    ///
+    /// If the `Verifier` was not preprocessed.
+    pub fn context(
+        &self,
+        opening_key: &OpeningKey,
+    ) -> Result<VerifierContext, Error> {
+        VerifierContext::with_transcript(
+            *self.verifier_key.as_ref().unwrap(),
+            self.preprocessed_transcript.clone(),
+            opening_key,
+        )
+    }
+
+    /// Creates a [`BatchVerifier`] for the circuit of this `Verifier`,
+    /// starting from its preprocessed transcript.
+    pub fn batch_verifier(&self, opening_key: &OpeningKey) -> BatchVerifier {
+        BatchVerifier {
+            transcript: self.preprocessed_transcript.clone(),
+            seeded: true,
+            opening_key: opening_key.clone(),
+            proofs: Vec::new(),
+        }
+    }
+}
+
+/// Verifies [`Proof`]s of a single circuit, computing what derives from its
+/// [`VerifierKey`] once instead of for every proof.
+///
+/// The context keeps the evaluation domain of the circuit, along with its
+/// generator, and the transcript already seeded with the key, so each
+/// verification only replays the proof itself.
+#[allow(missing_debug_implementations)]
+pub struct VerifierContext {
+    verifier_key: VerifierKey,
+    domain: EvaluationDomain,
+    transcript: Transcript,
+    opening_key: OpeningKey,
+}
+
+impl VerifierContext {
+    /// Creates a context verifying proofs against `verifier_key`, with a
+    /// transcript initialized with `label` and seeded with the key like
+    /// [`Verifier::preprocess`] does.
+    pub fn new(
+        label: &'static [u8],
+        verifier_key: VerifierKey,
+        opening_key: &OpeningKey,
+    ) -> Result<Self, Error> {
+        let mut transcript = Transcript::new(label);
+        verifier_key.seed_transcript(&mut transcript);
+
+        Self::with_transcript(verifier_key, transcript, opening_key)
+    }
+
+    fn with_transcript(
+        verifier_key: VerifierKey,
+        transcript: Transcript,
+        opening_key: &OpeningKey,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            domain: EvaluationDomain::new(verifier_key.n)?,
+            verifier_key,
+            transcript,
+            opening_key: opening_key.clone(),
+        })
+    }
+
+    /// Returns the [`VerifierKey`] proofs are verified against.
+    pub fn verifier_key(&self) -> &VerifierKey {
+        &self.verifier_key
+    }
+
+    /// Returns the evaluation domain of the circuit.
+    pub fn domain(&self) -> &EvaluationDomain {
+        &self.domain
+    }
+
+    /// Verifies a [`Proof`], like [`Verifier::verify`].
+    pub fn verify(
+        &self,
+        proof: &Proof,
+        public_inputs: &[BlsScalar],
+    ) -> Result<(), Error> {
+        proof.verify(
+            &self.verifier_key,
+            &self.domain,
+            &mut self.transcript.clone(),
+            &self.opening_key,
+            public_inputs,
+        )
+    }
+}
+
+/// Accumulates [`Proof`]s of the same circuit and verifies them all with a
+/// single pairing check.
+#[allow(missing_debug_implementations)]
+pub struct BatchVerifier {
+    transcript: Transcript,
+    /// Whether `transcript` is already seeded with the [`VerifierKey`].
+    seeded: bool,
+    opening_key: OpeningKey,
+    proofs: Vec<(Proof, Vec<BlsScalar>)>,
+}
+
+impl BatchVerifier {
+    /// Creates an empty `BatchVerifier` whose proofs are checked against a
+    /// transcript initialized with `label`.
+    ///
+    /// The transcript is seeded with the [`VerifierKey`] given to
+    /// [`BatchVerifier::verify_batch`] like [`Verifier::preprocess`] does,
+    /// after any message added with [`BatchVerifier::key_transcript`].
+    pub fn new(label: &'static [u8], opening_key: &OpeningKey) -> Self {
+        Self {
+            transcript: Transcript::new(label),
+            seeded: false,
+            opening_key: opening_key.clone(),
+            proofs: Vec::new(),
+        }
+    }
+
+    /// Keys the [`Transcript`] with additional seed information
+    /// Wrapper around [`Transcript::append_message`].
+    pub fn key_transcript(&mut self, label: &'static [u8], message: &[u8]) {
+        self.transcript.append_message(label, message);
+    }
+
+    /// Adds a [`Proof`] and its public inputs to the batch.
+    pub fn add_proof(&mut self, proof: Proof, public_inputs: Vec<BlsScalar>) {
+        self.proofs.push((proof, public_inputs));
+    }
+
+    /// Returns the number of proofs in the batch.
+    pub fn len(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Returns `true` if the batch contains no proofs.
+    pub fn is_empty(&self) -> bool {
+        self.proofs.is_empty()
+    }
+
+    /// Returns the transcript the proofs are verified against, seeded with
+    /// `verifier_key` unless it already is.
+    fn key_seeded_transcript(&self, verifier_key: &VerifierKey) -> Transcript {
+        let mut transcript = self.transcript.clone();
+        if !self.seeded {
+            verifier_key.seed_transcript(&mut transcript);
+        }
+
+        transcript
+    }
+
+    /// Verifies every [`Proof`] of the batch with a single pairing check.
+    ///
+    /// Each proof is folded into its pairing terms using its own transcript.
+    /// Its public inputs are then appended to that transcript, as their
+    /// count followed by each scalar, and the final state seeds the
+    /// coefficients of the random linear combination of all the terms. An
+    /// empty batch is valid.
+    ///
+    /// On failure, [`BatchVerifier::failing_proofs`] tells which proofs are
+    /// invalid.
+    pub fn verify_batch(
+        &self,
+        verifier_key: &VerifierKey,
+    ) -> Result<(), Error> {
+        let domain = EvaluationDomain::new(verifier_key.n)?;
+        let seeded_transcript = self.key_seeded_transcript(verifier_key);
+        let mut batch_transcript = Transcript::new(b"batch-verifier");
+        let mut terms = Vec::with_capacity(self.proofs.len());
+
+        for (proof, public_inputs) in &self.proofs {
+            let mut transcript = seeded_transcript.clone();
+            terms.push(proof.verification_terms(
+                verifier_key,
+                &domain,
+                &mut transcript,
+                &self.opening_key,
+                public_inputs,
+            )?);
+
+            // The proof transcript never absorbs the public inputs, so they
+            // are bound here, otherwise changes of the public inputs of
+            // several proofs could be chosen to cancel out in the sum.
+            transcript.append_u64(b"batch-pi-len", public_inputs.len() as u64);
+            public_inputs
+                .iter()
+                .for_each(|pi| transcript.append_scalar(b"batch-pi", pi));
+            let seed = transcript.challenge_scalar(b"batch-seed");
+            batch_transcript.append_scalar(b"batch-seed", &seed);
+        }
+
+        let mut total_c = G1Projective::identity();
+        let mut total_w = G1Projective::identity();
+        for (c, w) in terms {
+            let coefficient = batch_transcript.challenge_scalar(b"batch-coeff");
+            total_c += c * coefficient;
+            total_w += w * coefficient;
+        }
+
+        self.opening_key
+            .check_terms(total_c, total_w)
+            .map_err(|_| Error::ProofVerificationError)
+    }
+
+    /// Verifies each [`Proof`] of the batch individually, returning the
+    /// indices of the invalid ones in insertion order.
+    pub fn failing_proofs(&self, verifier_key: &VerifierKey) -> Vec<usize> {
+        // No proof verifies against a key without an evaluation domain
+        let domain = match EvaluationDomain::new(verifier_key.n) {
+            Ok(domain) => domain,
+            Err(_) => return (0..self.proofs.len()).collect(),
+        };
+        let transcript = self.key_seeded_transcript(verifier_key);
+
+        self.proofs
+            .iter()
+            .enumerate()
+            .filter(|(_, (proof, public_inputs))| {
+                proof
+                    .verify(
+                        verifier_key,
+                        &domain,
+                        &mut transcript.clone(),
+                        &self.opening_key,
+                        public_inputs,
+                    )
+                    .is_err()
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commitment_scheme::kzg10::PublicParameters;
+    use crate::constraint_system::helper::dummy_gadget;
+    use crate::proof_system::Prover;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_batch_verifier() -> Result<(), Error> {
+        let public_parameters = PublicParameters::setup(1 << 9, &mut OsRng)?;
+        let (ck, opening_key) = public_parameters.trim(1 << 8)?;
+
+        let mut proofs = Vec::new();
+        for _ in 0..3 {
+            let mut prover = Prover::new(b"batch");
+            dummy_gadget(20, prover.mut_cs());
+            prover.preprocess(&ck)?;
+            let public_inputs = prover.cs.construct_dense_pi_vec();
+            proofs.push((prover.prove(&ck)?, public_inputs));
+        }
+
+        let mut verifier = Verifier::new(b"batch");
+        dummy_gadget(20, verifier.mut_cs());
+        verifier.preprocess(&ck)?;
+        let verifier_key = verifier.verifier_key.unwrap();
+
+        let mut batch = verifier.batch_verifier(&opening_key);
+        assert!(batch.verify_batch(&verifier_key).is_ok());
+        proofs
+            .iter()
+            .cloned()
+            .for_each(|(proof, pi)| batch.add_proof(proof, pi));
+        assert_eq!(batch.len(), 3);
+        batch.verify_batch(&verifier_key)?;
+        assert!(batch.failing_proofs(&verifier_key).is_empty());
+
+        // A proof checked against the wrong public inputs spoils the batch
+        let (proof, mut public_inputs) = proofs[0].clone();
+        public_inputs[0] += BlsScalar::one();
+        batch.add_proof(proof, public_inputs);
+        assert!(matches!(
+            batch.verify_batch(&verifier_key),
+            Err(Error::ProofVerificationError)
+        ));
+        assert_eq!(batch.failing_proofs(&verifier_key), [3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_verifier_new() -> Result<(), Error> {
+        use crate::test_fixtures::{TinyCircuit, TinyFixture};
+
+        let mut fixture = TinyFixture::new()?;
+        let (proof, public_inputs) = fixture.prove(&TinyCircuit::default())?;
+        let verifier_key = fixture.verifier.verifier_key.unwrap();
+
+        // The key is seeded when the batch is verified
+        let mut batch = BatchVerifier::new(b"tiny", &fixture.opening_key);
+        batch.add_proof(proof.clone(), public_inputs.clone());
+        batch.add_proof(proof.clone(), public_inputs.clone());
+        batch.verify_batch(&verifier_key)?;
+        assert!(batch.failing_proofs(&verifier_key).is_empty());
+
+        let mut batch = BatchVerifier::new(b"other", &fixture.opening_key);
+        batch.add_proof(proof, public_inputs);
+        assert!(batch.verify_batch(&verifier_key).is_err());
+        assert_eq!(batch.failing_proofs(&verifier_key), [0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_verifier_cancelling_public_inputs() -> Result<(), Error> {
+        use crate::test_fixtures::{TinyCircuit, TinyFixture};
+
+        let mut fixture = TinyFixture::new()?;
+        let (proof, public_inputs) = fixture.prove(&TinyCircuit::default())?;
+        let verifier_key = fixture.verifier.verifier_key.unwrap();
+
+        // Opposite changes of the public inputs of the same proof shift its
+        // pairing terms by opposite amounts, which would cancel out if both
+        // proofs were combined with the same coefficient.
+        let mut raised = public_inputs.clone();
+        raised[0] += BlsScalar::one();
+        let mut lowered = public_inputs;
+        lowered[0] -= BlsScalar::one();
+
+        let mut batch = fixture.verifier.batch_verifier(&fixture.opening_key);
+        batch.add_proof(proof.clone(), raised);
+        batch.add_proof(proof, lowered);
+        assert!(matches!(
+            batch.verify_batch(&verifier_key),
+            Err(Error::ProofVerificationError)
+        ));
+        assert_eq!(batch.failing_proofs(&verifier_key), [0, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_with_digest_seeded_transcript() -> Result<(), Error> {
+        let public_parameters = PublicParameters::setup(1 << 9, &mut OsRng)?;
+        let (ck, opening_key) = public_parameters.trim(1 << 8)?;
+
+        let mut verifier = Verifier::new(b"digest");
+        dummy_gadget(20, verifier.mut_cs());
+        verifier.preprocess(&ck)?;
+        let digest = verifier.verifier_key.as_ref().unwrap().digest();
+
+        let mut prover = Prover::new(b"digest");
+        dummy_gadget(20, prover.mut_cs());
+        prover.preprocess_with_digest(&ck, &digest)?;
+        let public_inputs = prover.cs.construct_dense_pi_vec();
+        let proof = prover.prove(&ck)?;
+
+        // A verifier seeded with every commitment rejects the proof
+        assert!(verifier
+            .verify(&proof, &opening_key, &public_inputs)
+            .is_err());
+
+        // So does a verifier given the digest of another circuit
+        let mut other = Verifier::new(b"digest");
+        dummy_gadget(21, other.mut_cs());
+        assert!(matches!(
+            other.preprocess_with_digest(&ck, &digest),
+            Err(Error::MismatchedVerifierKeyDigest)
+        ));
+
+        let mut verifier = Verifier::new(b"digest");
+        dummy_gadget(20, verifier.mut_cs());
+        verifier.preprocess_with_digest(&ck, &digest)?;
+        verifier.verify(&proof, &opening_key, &public_inputs)
+    }
+
+    #[test]
+    fn test_linearization_commitment() -> Result<(), Error> {
+        use crate::test_fixtures::{TinyCircuit, TinyFixture};
+
+        let mut fixture = TinyFixture::new()?;
+        let (proof, public_inputs) = fixture.prove(&TinyCircuit::default())?;
+        fixture.verify(&proof, &public_inputs)?;
+
+        let verifier_key = fixture.verifier.verifier_key.unwrap();
+        let challenges = fixture.verifier.challenges(&proof);
+        assert_eq!(challenges, fixture.verifier.challenges(&proof));
+        let r_comm =
+            verifier_key.linearization_commitment(&proof, &challenges)?;
+        assert!(!r_comm.is_identity());
+
+        // The commitment follows the evaluations of the proof
+        let mut tampered = proof.clone();
+        tampered.evaluations.a_eval += BlsScalar::one();
+        assert_eq!(fixture.verifier.challenges(&tampered), challenges);
+        assert_ne!(
+            verifier_key.linearization_commitment(&tampered, &challenges)?,
+            r_comm
+        );
+
+        // The challenges follow its commitments
+        let mut tampered = proof;
+        tampered.z_comm = tampered.a_comm;
+        assert_ne!(fixture.verifier.challenges(&tampered), challenges);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verifier_context() -> Result<(), Error> {
+        use crate::test_fixtures::{TinyCircuit, TinyFixture};
+
+        let mut fixture = TinyFixture::new()?;
+        let (proof, public_inputs) = fixture.prove(&TinyCircuit::default())?;
+        let verifier_key = fixture.verifier.verifier_key.unwrap();
+
+        let context =
+            VerifierContext::new(b"tiny", verifier_key, &fixture.opening_key)?;
+        assert_eq!(context.verifier_key(), &verifier_key);
+        assert_eq!(context.domain(), &EvaluationDomain::new(verifier_key.n)?);
+        for _ in 0..2 {
+            context.verify(&proof, &public_inputs)?;
+        }
+
+        let context = fixture.verifier.context(&fixture.opening_key)?;
+        context.verify(&proof, &public_inputs)?;
+
+        let mut wrong_inputs = public_inputs.clone();
+        wrong_inputs[0] += BlsScalar::one();
+        assert!(matches!(
+            context.verify(&proof, &wrong_inputs),
+            Err(Error::ProofVerificationError)
+        ));
+
+        let context =
+            VerifierContext::new(b"other", verifier_key, &fixture.opening_key)?;
+        assert!(context.verify(&proof, &public_inputs).is_err());
+        Ok(())
+    }
 }
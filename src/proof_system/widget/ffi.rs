@@ -0,0 +1,147 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Fixed-layout representation of a [`VerifierKey`] for FFI.
+
+use super::{circuit_size_from_u64, VerifierKey};
+use crate::commitment_scheme::kzg10::Commitment;
+use crate::error::Error;
+use dusk_bytes::Serializable;
+
+/// C-compatible layout of a [`VerifierKey`], to hand a key to verifiers
+/// written in other languages without marshalling it byte by byte.
+///
+/// Every commitment is a compressed G1 point, serialized like
+/// `Commitment::to_bytes`. The fields follow the order of
+/// [`VerifierKey::serialized_commitments`].
+///
+/// The circuit size `n` is a plain `u64` and therefore uses the native
+/// endianness of the host, unlike the little-endian encoding of the size in
+/// `VerifierKey::to_bytes`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[repr(C)]
+pub struct VerifierKeyC {
+    /// Circuit size (not padded to a power of two), in native endianness.
+    pub n: u64,
+    /// Multiplication selector commitment
+    pub q_m: [u8; Commitment::SIZE],
+    /// Left wire selector commitment
+    pub q_l: [u8; Commitment::SIZE],
+    /// Right wire selector commitment
+    pub q_r: [u8; Commitment::SIZE],
+    /// Output wire selector commitment
+    pub q_o: [u8; Commitment::SIZE],
+    /// Fourth wire selector commitment
+    pub q_4: [u8; Commitment::SIZE],
+    /// Constant selector commitment
+    pub q_c: [u8; Commitment::SIZE],
+    /// Arithmetic gate selector commitment
+    pub q_arith: [u8; Commitment::SIZE],
+    /// Logic gate selector commitment
+    pub q_logic: [u8; Commitment::SIZE],
+    /// Range gate selector commitment
+    pub q_range: [u8; Commitment::SIZE],
+    /// Fixed base curve addition selector commitment
+    pub q_fixed_group_add: [u8; Commitment::SIZE],
+    /// Variable base curve addition selector commitment
+    pub q_variable_group_add: [u8; Commitment::SIZE],
+    /// Left sigma commitment of the permutation
+    pub left_sigma: [u8; Commitment::SIZE],
+    /// Right sigma commitment of the permutation
+    pub right_sigma: [u8; Commitment::SIZE],
+    /// Out sigma commitment of the permutation
+    pub out_sigma: [u8; Commitment::SIZE],
+    /// Fourth sigma commitment of the permutation
+    pub fourth_sigma: [u8; Commitment::SIZE],
+}
+
+impl VerifierKey {
+    /// Exports the [`VerifierKey`] to its fixed-layout [`VerifierKeyC`]
+    /// representation.
+    pub fn to_c_repr(&self) -> VerifierKeyC {
+        let mut c = [[0u8; Commitment::SIZE]; 15];
+        for (bytes, (_, commitment)) in
+            c.iter_mut().zip(self.serialized_commitments())
+        {
+            *bytes = commitment.to_bytes();
+        }
+
+        VerifierKeyC {
+            n: self.n as u64,
+            q_m: c[0],
+            q_l: c[1],
+            q_r: c[2],
+            q_o: c[3],
+            q_4: c[4],
+            q_c: c[5],
+            q_arith: c[6],
+            q_logic: c[7],
+            q_range: c[8],
+            q_fixed_group_add: c[9],
+            q_variable_group_add: c[10],
+            left_sigma: c[11],
+            right_sigma: c[12],
+            out_sigma: c[13],
+            fourth_sigma: c[14],
+        }
+    }
+
+    /// Imports a [`VerifierKey`] from its [`VerifierKeyC`] representation,
+    /// decompressing each commitment and checking that it lies in the
+    /// prime-order subgroup of G1, like `VerifierKey::from_bytes` does.
+    pub fn from_c_repr(repr: &VerifierKeyC) -> Result<VerifierKey, Error> {
+        let n = circuit_size_from_u64(repr.n)?;
+        let bytes = [
+            &repr.q_m,
+            &repr.q_l,
+            &repr.q_r,
+            &repr.q_o,
+            &repr.q_4,
+            &repr.q_c,
+            &repr.q_arith,
+            &repr.q_logic,
+            &repr.q_range,
+            &repr.q_fixed_group_add,
+            &repr.q_variable_group_add,
+            &repr.left_sigma,
+            &repr.right_sigma,
+            &repr.out_sigma,
+            &repr.fourth_sigma,
+        ];
+        let mut commitments = [Commitment::default(); 15];
+        for (commitment, bytes) in commitments.iter_mut().zip(bytes) {
+            *commitment = Commitment::from_bytes(bytes)?;
+        }
+
+        Self::from_commitments(n, commitments)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_fixtures::preprocessed_dummy_key;
+
+    #[test]
+    fn test_verifier_key_c_repr() {
+        let (commit_key, prover_key) = preprocessed_dummy_key(20);
+        let verifier_key = prover_key.to_verifier_key(&commit_key).unwrap();
+
+        let repr = verifier_key.to_c_repr();
+        assert_eq!(repr.n, verifier_key.n as u64);
+        assert_eq!(repr.q_m[..], verifier_key.to_bytes()[8..56]);
+        assert_eq!(VerifierKey::from_c_repr(&repr).unwrap(), verifier_key);
+
+        let mut invalid = repr;
+        invalid.n = 0;
+        assert!(VerifierKey::from_c_repr(&invalid).is_err());
+
+        let mut invalid = repr;
+        invalid.out_sigma = [0xff; Commitment::SIZE];
+        assert!(VerifierKey::from_c_repr(&invalid).is_err());
+    }
+}
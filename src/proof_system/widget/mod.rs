@@ -6,16 +6,43 @@
 
 pub mod arithmetic;
 pub mod ecc;
+#[cfg(feature = "ffi")]
+mod ffi;
 pub mod logic;
 pub mod permutation;
 pub mod range;
 use crate::commitment_scheme::kzg10::Commitment;
+use crate::error::Error;
+use core::convert::TryFrom;
+use core::hash::{Hash, Hasher};
+use dusk_bls12_381::G1Affine;
 use dusk_bytes::{DeserializableSlice, Serializable};
+#[cfg(feature = "ffi")]
+pub use ffi::VerifierKeyC;
+
+/// Converts a serialized circuit size into a `usize`, checking that it fits on
+/// the current target and that it can still be padded to a power of two.
+pub(crate) fn circuit_size_from_u64(n: u64) -> Result<usize, Error> {
+    usize::try_from(n)
+        .ok()
+        .filter(|size| size.checked_next_power_of_two().is_some())
+        .ok_or(Error::CircuitSizeOverflow { n })
+}
 
 /// PLONK circuit Verification Key.
 ///
 /// This structure is used by the Verifier in order to verify a
 /// [`Proof`](super::Proof).
+///
+/// # Commitment order
+///
+/// The commitments are serialized in the order of
+/// [`VerifierKey::serialized_commitments`], but appended to the transcript in
+/// the order of [`VerifierKey::commitments`]: `q_c` comes before `q_4`,
+/// `q_range` before `q_logic` and `q_variable_group_add` before
+/// `q_fixed_group_add`. Both orders are kept as they are, since changing
+/// either of them would invalidate the serialized keys or the proofs
+/// already in use.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub struct VerifierKey {
     /// Circuit size (not padded to a power of two).
@@ -32,10 +59,51 @@ pub struct VerifierKey {
     pub(crate) variable_base: ecc::curve_addition::VerifierKey,
     /// VerifierKey for permutation checks
     pub(crate) permutation: permutation::VerifierKey,
+    /// Whether the permutation argument is skipped, see
+    /// `VerifierKey::disable_permutation_unsound`.
+    #[cfg(feature = "debug-unsound")]
+    pub(crate) permutation_disabled: bool,
+}
+
+/// Names each field of a [`VerifierKey`], as reported by `VerifierKey::diff`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum VerifierKeyField {
+    /// Circuit size
+    N,
+    /// Multiplication selector commitment
+    QM,
+    /// Left wire selector commitment
+    QL,
+    /// Right wire selector commitment
+    QR,
+    /// Output wire selector commitment
+    QO,
+    /// Constant selector commitment
+    QC,
+    /// Fourth wire selector commitment
+    Q4,
+    /// Arithmetic gate selector commitment
+    QArith,
+    /// Range gate selector commitment
+    QRange,
+    /// Logic gate selector commitment
+    QLogic,
+    /// Variable base curve addition selector commitment
+    QVariableGroupAdd,
+    /// Fixed base curve addition selector commitment
+    QFixedGroupAdd,
+    /// Left sigma commitment of the permutation
+    LeftSigma,
+    /// Right sigma commitment of the permutation
+    RightSigma,
+    /// Out sigma commitment of the permutation
+    OutSigma,
+    /// Fourth sigma commitment of the permutation
+    FourthSigma,
 }
 
 impl Serializable<{ 15 * Commitment::SIZE + u64::SIZE }> for VerifierKey {
-    type Error = dusk_bytes::Error;
+    type Error = Error;
 
     #[allow(unused_must_use)]
     fn to_bytes(&self) -> [u8; Self::SIZE] {
@@ -44,55 +112,328 @@ impl Serializable<{ 15 * Commitment::SIZE + u64::SIZE }> for VerifierKey {
         let mut writer = &mut buff[..];
 
         writer.write(&(self.n as u64).to_bytes());
-        writer.write(&self.arithmetic.q_m.to_bytes());
-        writer.write(&self.arithmetic.q_l.to_bytes());
-        writer.write(&self.arithmetic.q_r.to_bytes());
-        writer.write(&self.arithmetic.q_o.to_bytes());
-        writer.write(&self.arithmetic.q_4.to_bytes());
-        writer.write(&self.arithmetic.q_c.to_bytes());
-        writer.write(&self.arithmetic.q_arith.to_bytes());
-        writer.write(&self.logic.q_logic.to_bytes());
-        writer.write(&self.range.q_range.to_bytes());
-        writer.write(&self.fixed_base.q_fixed_group_add.to_bytes());
-        writer.write(&self.variable_base.q_variable_group_add.to_bytes());
-        writer.write(&self.permutation.left_sigma.to_bytes());
-        writer.write(&self.permutation.right_sigma.to_bytes());
-        writer.write(&self.permutation.out_sigma.to_bytes());
-        writer.write(&self.permutation.fourth_sigma.to_bytes());
+        for (_, commitment) in self.serialized_commitments() {
+            writer.write(&commitment.to_bytes());
+        }
 
         buff
     }
 
+    /// Deserializes a [`VerifierKey`], decompressing each commitment and
+    /// checking that it lies in the prime-order subgroup of G1.
+    ///
+    /// For trusted inputs these checks can be skipped with
+    /// [`VerifierKey::from_slice_unchecked`].
     fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<VerifierKey, Self::Error> {
         let mut buffer = &buf[..];
+        let n = circuit_size_from_u64(u64::from_reader(&mut buffer)?)?;
+        let (c, _) = Commitment::read_array::<15>(buffer)?;
 
         Ok(Self::from_polynomial_commitments(
-            u64::from_reader(&mut buffer)? as usize,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
-            Commitment::from_reader(&mut buffer)?,
+            n, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9],
+            c[10], c[11], c[12], c[13], c[14],
         ))
     }
 }
 
+/// Hashes the serialized form of the key, so that a [`VerifierKey`] can be
+/// used as a `HashMap` key.
+///
+/// The commitments are hashed compressed, since the raw coordinates of the
+/// point at infinity are not unique while its compressed encoding is, which
+/// keeps the hash consistent with `PartialEq`.
+#[allow(clippy::derive_hash_xor_eq)]
+impl Hash for VerifierKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state);
+    }
+}
+
 impl VerifierKey {
+    /// Size in bytes of the raw representation of a [`VerifierKey`].
+    pub const RAW_SIZE: usize = u64::SIZE + 15 * G1Affine::RAW_SIZE;
+
+    /// Serialize the [`VerifierKey`] into its raw representation.
+    ///
+    /// The commitments are stored uncompressed so they can be restored by
+    /// [`VerifierKey::from_slice_unchecked`] without the decompression and
+    /// subgroup checks performed by `VerifierKey::from_bytes`.
+    pub fn to_raw_bytes(&self) -> [u8; Self::RAW_SIZE] {
+        let mut bytes = [0u8; Self::RAW_SIZE];
+        bytes[..u64::SIZE].copy_from_slice(&(self.n as u64).to_le_bytes());
+
+        let commitments = [
+            &self.arithmetic.q_m,
+            &self.arithmetic.q_l,
+            &self.arithmetic.q_r,
+            &self.arithmetic.q_o,
+            &self.arithmetic.q_4,
+            &self.arithmetic.q_c,
+            &self.arithmetic.q_arith,
+            &self.logic.q_logic,
+            &self.range.q_range,
+            &self.fixed_base.q_fixed_group_add,
+            &self.variable_base.q_variable_group_add,
+            &self.permutation.left_sigma,
+            &self.permutation.right_sigma,
+            &self.permutation.out_sigma,
+            &self.permutation.fourth_sigma,
+        ];
+        bytes[u64::SIZE..]
+            .chunks_exact_mut(G1Affine::RAW_SIZE)
+            .zip(commitments.iter())
+            .for_each(|(c, commitment)| {
+                c.copy_from_slice(&commitment.0.to_raw_bytes())
+            });
+
+        bytes
+    }
+
+    /// Deserialize a [`VerifierKey`] from a set of bytes created by
+    /// [`VerifierKey::to_raw_bytes`], checking that every commitment is a
+    /// valid point of the prime-order subgroup.
+    ///
+    /// The uncompressed points spare the square root needed to decompress
+    /// the commitments read by `VerifierKey::from_bytes`, at the cost of a
+    /// key about twice as large.
+    pub fn from_raw_bytes(
+        bytes: &[u8; Self::RAW_SIZE],
+    ) -> Result<VerifierKey, Error> {
+        let mut n = [0u8; u64::SIZE];
+        n.copy_from_slice(&bytes[..u64::SIZE]);
+        let n = circuit_size_from_u64(u64::from_le_bytes(n))?;
+
+        let mut commitments = bytes[u64::SIZE..]
+            .chunks_exact(G1Affine::RAW_SIZE)
+            .map(Commitment::from_raw_slice);
+        let mut next =
+            || commitments.next().unwrap_or(Err(Error::NotEnoughBytes));
+
+        Ok(Self::from_polynomial_commitments(
+            n,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+            next()?,
+        ))
+    }
+
+    /// Deserialize a [`VerifierKey`] from a set of bytes created by
+    /// [`VerifierKey::to_raw_bytes`], without checking the circuit size nor
+    /// the commitments.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be the output of [`VerifierKey::to_raw_bytes`] called on
+    /// a valid key, such as one read with `VerifierKey::from_bytes` or
+    /// [`VerifierKey::from_raw_bytes`]. Other bytes do not cause memory
+    /// errors, but yield points outside of the G1 subgroup, or not on the
+    /// curve at all, and a key whose verifications are meaningless.
+    pub unsafe fn from_slice_unchecked(bytes: &[u8; Self::RAW_SIZE]) -> Self {
+        let mut n = [0u8; u64::SIZE];
+        n.copy_from_slice(&bytes[..u64::SIZE]);
+        let n = u64::from_le_bytes(n) as usize;
+
+        let mut c = [Commitment::default(); 15];
+        for (commitment, bytes) in c
+            .iter_mut()
+            .zip(bytes[u64::SIZE..].chunks_exact(G1Affine::RAW_SIZE))
+        {
+            *commitment = Commitment(G1Affine::from_slice_unchecked(bytes));
+        }
+
+        Self::from_polynomial_commitments(
+            n, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9],
+            c[10], c[11], c[12], c[13], c[14],
+        )
+    }
+
     /// Returns the Circuit size padded to the next power of two.
     pub const fn padded_circuit_size(&self) -> usize {
         self.n.next_power_of_two()
     }
 
+    /// Returns `true` if proofs for this key and for `other` can be
+    /// aggregated, see [`VerifierKey::assert_compatible`].
+    pub fn compatible_with(&self, other: &Self) -> bool {
+        self.assert_compatible(other).is_ok()
+    }
+
+    /// Checks that proofs for this key and for `other` can be aggregated.
+    ///
+    /// Both circuits must be padded to the same size, so that their proofs
+    /// are evaluated over the same domain and opened with the same commit
+    /// key. Returns [`Error::IncompatibleVerifierKeys`] with both padded
+    /// sizes otherwise.
+    pub fn assert_compatible(&self, other: &Self) -> Result<(), Error> {
+        let (size, other_size) =
+            (self.padded_circuit_size(), other.padded_circuit_size());
+        if size != other_size {
+            return Err(Error::IncompatibleVerifierKeys { size, other_size });
+        }
+
+        Ok(())
+    }
+
+    /// Returns an iterator over every [`Commitment`] of the [`VerifierKey`],
+    /// labeled as in the transcript and following the same order.
+    ///
+    /// See [`VerifierKey`] for how this order differs from the serialized
+    /// one.
+    pub fn commitments(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, &Commitment)> {
+        core::array::IntoIter::new([
+            ("q_m", &self.arithmetic.q_m),
+            ("q_l", &self.arithmetic.q_l),
+            ("q_r", &self.arithmetic.q_r),
+            ("q_o", &self.arithmetic.q_o),
+            ("q_c", &self.arithmetic.q_c),
+            ("q_4", &self.arithmetic.q_4),
+            ("q_arith", &self.arithmetic.q_arith),
+            ("q_range", &self.range.q_range),
+            ("q_logic", &self.logic.q_logic),
+            (
+                "q_variable_group_add",
+                &self.variable_base.q_variable_group_add,
+            ),
+            ("q_fixed_group_add", &self.fixed_base.q_fixed_group_add),
+            ("left_sigma", &self.permutation.left_sigma),
+            ("right_sigma", &self.permutation.right_sigma),
+            ("out_sigma", &self.permutation.out_sigma),
+            ("fourth_sigma", &self.permutation.fourth_sigma),
+        ])
+    }
+
+    /// Returns an iterator over every [`Commitment`] of the [`VerifierKey`],
+    /// labeled as in the transcript but following the order used by
+    /// `to_bytes`.
+    pub fn serialized_commitments(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, &Commitment)> {
+        core::array::IntoIter::new([
+            ("q_m", &self.arithmetic.q_m),
+            ("q_l", &self.arithmetic.q_l),
+            ("q_r", &self.arithmetic.q_r),
+            ("q_o", &self.arithmetic.q_o),
+            ("q_4", &self.arithmetic.q_4),
+            ("q_c", &self.arithmetic.q_c),
+            ("q_arith", &self.arithmetic.q_arith),
+            ("q_logic", &self.logic.q_logic),
+            ("q_range", &self.range.q_range),
+            ("q_fixed_group_add", &self.fixed_base.q_fixed_group_add),
+            (
+                "q_variable_group_add",
+                &self.variable_base.q_variable_group_add,
+            ),
+            ("left_sigma", &self.permutation.left_sigma),
+            ("right_sigma", &self.permutation.right_sigma),
+            ("out_sigma", &self.permutation.out_sigma),
+            ("fourth_sigma", &self.permutation.fourth_sigma),
+        ])
+    }
+
+    /// Checks that the [`VerifierKey`] does not describe a degenerate
+    /// circuit, returning [`Error::DegenerateCircuit`] if every arithmetic
+    /// selector commitment is the identity point.
+    ///
+    /// Deserialization does not run this check, so keys coming from
+    /// untrusted sources should be validated explicitly.
+    pub fn validate(&self) -> Result<(), Error> {
+        let arithmetic = &self.arithmetic;
+        let selectors = [
+            arithmetic.q_m,
+            arithmetic.q_l,
+            arithmetic.q_r,
+            arithmetic.q_o,
+            arithmetic.q_4,
+            arithmetic.q_c,
+            arithmetic.q_arith,
+        ];
+
+        if selectors.iter().all(Commitment::is_identity) {
+            return Err(Error::DegenerateCircuit);
+        }
+
+        Ok(())
+    }
+
+    /// Constructs a [`VerifierKey`] for a circuit of size `n` from
+    /// commitments computed elsewhere, e.g. during a setup ceremony.
+    ///
+    /// The commitments must follow the order used by `to_bytes`: `q_m`,
+    /// `q_l`, `q_r`, `q_o`, `q_4`, `q_c`, `q_arith`, `q_logic`, `q_range`,
+    /// `q_fixed_group_add`, `q_variable_group_add`, `left_sigma`,
+    /// `right_sigma`, `out_sigma` and `fourth_sigma`.
+    pub fn from_commitments(
+        n: usize,
+        commitments: [Commitment; 15],
+    ) -> Result<VerifierKey, Error> {
+        if n == 0 {
+            return Err(Error::CircuitSizeIsZero);
+        }
+        let n = circuit_size_from_u64(n as u64)?;
+
+        let c = commitments;
+        Ok(Self::from_polynomial_commitments(
+            n, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9],
+            c[10], c[11], c[12], c[13], c[14],
+        ))
+    }
+
+    /// Constructs a [`VerifierKey`] like [`VerifierKey::from_commitments`],
+    /// first checking that every commitment lies on the BLS12-381 G1 curve
+    /// and in its prime-order subgroup.
+    ///
+    /// Returns [`Error::PointNotOnCurve`] with the position of the first
+    /// invalid commitment. Commitments coming from untrusted sources, such as
+    /// the participants of a setup ceremony, should go through this check.
+    pub fn from_commitments_validated(
+        n: usize,
+        commitments: [Commitment; 15],
+    ) -> Result<VerifierKey, Error> {
+        let invalid = commitments.iter().position(|commitment| {
+            let point = commitment.0;
+            !bool::from(point.is_on_curve() & point.is_torsion_free())
+        });
+        if let Some(index) = invalid {
+            return Err(Error::PointNotOnCurve { index });
+        }
+
+        Self::from_commitments(n, commitments)
+    }
+
+    /// Serializes the commitments of a [`VerifierKey`] like `to_bytes` does,
+    /// without the circuit size in front of them.
+    pub fn commitments_to_bytes(&self) -> [u8; 15 * Commitment::SIZE] {
+        let mut bytes = [0u8; 15 * Commitment::SIZE];
+        bytes.copy_from_slice(&self.to_bytes()[u64::SIZE..]);
+
+        bytes
+    }
+
+    /// Deserializes the output of [`VerifierKey::commitments_to_bytes`] into
+    /// a [`VerifierKey`] for a circuit of size `n`, checking the commitments
+    /// like `from_bytes` does.
+    pub fn from_commitments_bytes(
+        n: usize,
+        bytes: &[u8; 15 * Commitment::SIZE],
+    ) -> Result<VerifierKey, Error> {
+        let (commitments, _) = Commitment::read_array(bytes)?;
+        Self::from_commitments(n, commitments)
+    }
+
     /// Constructs a [`VerifierKey`] from the widget VerifierKey's that are
     /// constructed based on the selector polynomial commitments and the
     /// sigma polynomial commitments.
@@ -150,59 +491,483 @@ impl VerifierKey {
             fixed_base,
             variable_base,
             permutation,
+            #[cfg(feature = "debug-unsound")]
+            permutation_disabled: false,
         }
     }
+
+    /// Skips the permutation argument when verifying proofs with this key,
+    /// to match a prover key on which
+    /// `ProverKey::disable_permutation_unsound` was called.
+    ///
+    /// # Warning
+    ///
+    /// __This is unsound: the copy constraints of the circuit are no longer
+    /// checked, so proofs of witnesses whose wires hold arbitrary values are
+    /// accepted.__ It is only meant to test the arithmetic of new gates in
+    /// isolation, and must never be used in production. The flag is not
+    /// serialized.
+    #[cfg(feature = "debug-unsound")]
+    pub fn disable_permutation_unsound(&mut self) {
+        self.permutation_disabled = true;
+    }
 }
 
 #[cfg(feature = "alloc")]
 pub(crate) mod alloc {
     use super::*;
     use crate::{
-        error::Error,
+        commitment_scheme::kzg10::{CommitKey, CommitmentCache},
         fft::{EvaluationDomain, Evaluations, Polynomial},
         transcript::TranscriptProtocol,
     };
     use ::alloc::vec::Vec;
     use dusk_bls12_381::BlsScalar;
+    use dusk_bytes::Error as DuskBytesError;
     use merlin::Transcript;
 
     impl VerifierKey {
+        /// Returns `false` if the permutation argument was disabled with
+        /// `VerifierKey::disable_permutation_unsound`.
+        #[cfg(feature = "debug-unsound")]
+        pub(crate) fn permutation_enabled(&self) -> bool {
+            !self.permutation_disabled
+        }
+
+        /// Returns `true`, since the permutation argument can only be disabled
+        /// with the `debug-unsound` feature.
+        #[cfg(not(feature = "debug-unsound"))]
+        pub(crate) fn permutation_enabled(&self) -> bool {
+            true
+        }
+
         /// Adds the circuit description to the transcript
         pub(crate) fn seed_transcript(&self, transcript: &mut Transcript) {
-            transcript.append_commitment(b"q_m", &self.arithmetic.q_m);
-            transcript.append_commitment(b"q_l", &self.arithmetic.q_l);
-            transcript.append_commitment(b"q_r", &self.arithmetic.q_r);
-            transcript.append_commitment(b"q_o", &self.arithmetic.q_o);
-            transcript.append_commitment(b"q_c", &self.arithmetic.q_c);
-            transcript.append_commitment(b"q_4", &self.arithmetic.q_4);
-            transcript.append_commitment(b"q_arith", &self.arithmetic.q_arith);
-            transcript.append_commitment(b"q_range", &self.range.q_range);
-            transcript.append_commitment(b"q_logic", &self.logic.q_logic);
-            transcript.append_commitment(
-                b"q_variable_group_add",
-                &self.variable_base.q_variable_group_add,
-            );
-            transcript.append_commitment(
-                b"q_fixed_group_add",
-                &self.fixed_base.q_fixed_group_add,
-            );
-
-            transcript
-                .append_commitment(b"left_sigma", &self.permutation.left_sigma);
-            transcript.append_commitment(
-                b"right_sigma",
-                &self.permutation.right_sigma,
-            );
-            transcript
-                .append_commitment(b"out_sigma", &self.permutation.out_sigma);
-            transcript.append_commitment(
-                b"fourth_sigma",
-                &self.permutation.fourth_sigma,
-            );
+            for (label, commitment) in self.commitments() {
+                transcript.append_commitment(label.as_bytes(), commitment);
+            }
 
             // Append circuit size to transcript
             transcript.circuit_domain_sep(self.n as u64);
         }
+
+        /// Adds the circuit description to the transcript, namespaced by
+        /// `ctx`.
+        ///
+        /// `ctx` is appended as a single `b"dom-sep"` message, followed by the
+        /// messages of `seed_transcript` with their usual, unprefixed labels.
+        /// Labels are not rewritten: merlin only accepts `'static` labels, so
+        /// they cannot be prefixed at runtime. Since merlin frames every
+        /// message with its length, transcripts seeded with different
+        /// contexts, or without any, diverge from the separator on, and so do
+        /// all the challenges drawn from them.
+        ///
+        /// An outer protocol sharing the transcript, for instance when
+        /// composing proofs recursively, is thus kept apart from the inner
+        /// circuit as long as it appends its own messages under a different
+        /// context or before the separator.
+        pub fn seed_transcript_with_context(
+            &self,
+            transcript: &mut Transcript,
+            ctx: &[u8],
+        ) {
+            transcript.append_message(b"dom-sep", ctx);
+            self.seed_transcript(transcript);
+        }
+
+        /// Returns the BLAKE2b hash, with a 32 bytes output and neither key
+        /// nor personalization, of the bytes returned by
+        /// [`VerifierKey::transcript_bytes`].
+        ///
+        /// This is the digest expected by
+        /// [`VerifierKey::seed_transcript_digest`].
+        pub fn digest(&self) -> [u8; 32] {
+            let hash = blake2b_simd::Params::new()
+                .hash_length(32)
+                .to_state()
+                .update(&self.transcript_bytes())
+                .finalize();
+
+            let mut digest = [0u8; 32];
+            digest.copy_from_slice(hash.as_bytes());
+            digest
+        }
+
+        /// Adds the circuit description to the transcript through a
+        /// precomputed `digest` of the [`VerifierKey`], instead of appending
+        /// each of its commitments and the circuit size.
+        ///
+        /// The `b"dom-sep"` message `b"vk_digest"` is appended, followed by
+        /// `digest` labeled `b"vk_digest"`. This trades the fifteen
+        /// commitments for a single message, which matters when the
+        /// transcript itself is constrained in a recursive circuit.
+        ///
+        /// `digest` must be the [`VerifierKey::digest`] of this key, since the
+        /// challenges are bound to the circuit only through it, otherwise
+        /// [`Error::MismatchedVerifierKeyDigest`] is returned and nothing is
+        /// appended. The resulting transcript differs from the one seeded by
+        /// `seed_transcript`, so the prover and the verifier must both be
+        /// preprocessed with `preprocess_with_digest`.
+        pub fn seed_transcript_digest(
+            &self,
+            transcript: &mut Transcript,
+            digest: &[u8; 32],
+        ) -> Result<(), Error> {
+            if digest != &self.digest() {
+                return Err(Error::MismatchedVerifierKeyDigest);
+            }
+
+            transcript.append_message(b"dom-sep", b"vk_digest");
+            transcript.append_message(b"vk_digest", digest);
+            Ok(())
+        }
+
+        /// Deserializes many [`VerifierKey`]s created by
+        /// [`VerifierKey::to_raw_bytes`], as [`VerifierKey::from_raw_bytes`]
+        /// would one at a time.
+        ///
+        /// Instead of a subgroup check per commitment, the commitments of all
+        /// the keys are validated together by [`Commitment::batch_validate`],
+        /// which gets cheaper per key as more keys are loaded. The index of an
+        /// [`Error::PointNotOnCurve`] counts the commitments across all the
+        /// keys, those of the `i`-th key being at `15 * i` up to
+        /// `15 * i + 14`.
+        ///
+        /// Compressed keys are always checked while being decompressed by
+        /// `VerifierKey::from_bytes`, so only the raw representation benefits
+        /// from batching the checks.
+        pub fn from_raw_bytes_batch_validated(
+            keys: &[[u8; VerifierKey::RAW_SIZE]],
+        ) -> Result<Vec<VerifierKey>, Error> {
+            let mut sizes = Vec::with_capacity(keys.len());
+            let mut commitments = Vec::with_capacity(15 * keys.len());
+            for bytes in keys {
+                let mut n = [0u8; u64::SIZE];
+                n.copy_from_slice(&bytes[..u64::SIZE]);
+                sizes.push(circuit_size_from_u64(u64::from_le_bytes(n))?);
+
+                for c in bytes[u64::SIZE..].chunks_exact(G1Affine::RAW_SIZE) {
+                    commitments
+                        .push(Commitment::from_raw_slice_unvalidated(c)?);
+                }
+            }
+            Commitment::batch_validate(&commitments)?;
+
+            Ok(sizes
+                .into_iter()
+                .zip(commitments.chunks_exact(15))
+                .map(|(n, c)| {
+                    Self::from_polynomial_commitments(
+                        n, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7],
+                        c[8], c[9], c[10], c[11], c[12], c[13], c[14],
+                    )
+                })
+                .collect())
+        }
+
+        /// Returns the messages appended to the transcript by
+        /// `seed_transcript`, in the same order and along with their labels.
+        ///
+        /// Each message is framed as merlin frames it: its label, followed by
+        /// its length as a little-endian `u32`, followed by the message. The
+        /// messages are the compressed commitments, labeled as in
+        /// [`VerifierKey::commitments`], then `b"circuit_size"` labeled
+        /// `b"dom-sep"` and the circuit size as a little-endian `u64` labeled
+        /// `b"n"`. Note this is not the order used by `to_bytes`.
+        pub fn transcript_bytes(&self) -> Vec<u8> {
+            fn append(bytes: &mut Vec<u8>, label: &[u8], message: &[u8]) {
+                bytes.extend_from_slice(label);
+                bytes.extend_from_slice(&(message.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(message);
+            }
+
+            let mut bytes = Vec::new();
+            for (label, commitment) in self.commitments() {
+                append(&mut bytes, label.as_bytes(), &commitment.to_bytes());
+            }
+            append(&mut bytes, b"dom-sep", b"circuit_size");
+            append(&mut bytes, b"n", &(self.n as u64).to_le_bytes());
+
+            bytes
+        }
+
+        /// Returns the fields in which `self` and `other` differ, following
+        /// the order of [`VerifierKey::commitments`].
+        pub fn diff(&self, other: &Self) -> Vec<VerifierKeyField> {
+            use VerifierKeyField::*;
+
+            let mut fields = Vec::new();
+            if self.n != other.n {
+                fields.push(N);
+            }
+
+            let commitment_fields = [
+                QM,
+                QL,
+                QR,
+                QO,
+                QC,
+                Q4,
+                QArith,
+                QRange,
+                QLogic,
+                QVariableGroupAdd,
+                QFixedGroupAdd,
+                LeftSigma,
+                RightSigma,
+                OutSigma,
+                FourthSigma,
+            ];
+            fields.extend(
+                commitment_fields
+                    .iter()
+                    .zip(self.commitments().zip(other.commitments()))
+                    .filter(|(_, ((_, a), (_, b)))| a != b)
+                    .map(|(field, _)| *field),
+            );
+
+            fields
+        }
+    }
+
+    /// Names each field of a [`ProverKey`], as reported by
+    /// [`ProverKey::diff`].
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub enum ProverKeyField {
+        /// Circuit size
+        N,
+        /// Multiplication selector
+        QM,
+        /// Left wire selector
+        QL,
+        /// Right wire selector
+        QR,
+        /// Output wire selector
+        QO,
+        /// Constant selector
+        QC,
+        /// Fourth wire selector
+        Q4,
+        /// Arithmetic gate selector
+        QArith,
+        /// Logic gate selector
+        QLogic,
+        /// Range gate selector
+        QRange,
+        /// Fixed base curve addition selector
+        QFixedGroupAdd,
+        /// Variable base curve addition selector
+        QVariableGroupAdd,
+        /// Left sigma of the permutation
+        LeftSigma,
+        /// Right sigma of the permutation
+        RightSigma,
+        /// Out sigma of the permutation
+        OutSigma,
+        /// Fourth sigma of the permutation
+        FourthSigma,
+        /// Linear evaluations of the permutation
+        LinearEvaluations,
+        /// Evaluations of the vanishing polynomial over the `4n` coset
+        VHCoset,
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl zeroize::Zeroize for ProverKey {
+        fn zeroize(&mut self) {
+            // Shared selectors are cloned into every widget using them, so
+            // each copy is cleared.
+            let polys_evals = [
+                &mut self.arithmetic.q_m,
+                &mut self.arithmetic.q_l,
+                &mut self.arithmetic.q_r,
+                &mut self.arithmetic.q_o,
+                &mut self.arithmetic.q_4,
+                &mut self.arithmetic.q_c,
+                &mut self.arithmetic.q_arith,
+                &mut self.logic.q_logic,
+                &mut self.logic.q_c,
+                &mut self.range.q_range,
+                &mut self.fixed_base.q_l,
+                &mut self.fixed_base.q_r,
+                &mut self.fixed_base.q_c,
+                &mut self.fixed_base.q_fixed_group_add,
+                &mut self.variable_base.q_variable_group_add,
+            ];
+            for (poly, evals) in polys_evals {
+                poly.zeroize();
+                evals.zeroize();
+            }
+            self.permutation.zeroize();
+            self.v_h_coset_4n.zeroize();
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl Drop for ProverKey {
+        fn drop(&mut self) {
+            zeroize::Zeroize::zeroize(self);
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl zeroize::ZeroizeOnDrop for ProverKey {}
+
+    /// Estimate, in bytes, of the memory needed to compute a
+    /// [`Proof`](crate::proof_system::Proof), as returned by
+    /// [`ProverKey::memory_estimate`].
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub struct MemoryEstimate {
+        /// Selector polynomials and their `4n` coset evaluations held by the
+        /// [`ProverKey`]
+        pub key_bytes: usize,
+        /// Wire values and the wire, permutation and public inputs
+        /// polynomials
+        pub witness_bytes: usize,
+        /// `4n` coset evaluations of the witness polynomials and of the
+        /// quotient numerator terms
+        pub coset_bytes: usize,
+        /// Quotient polynomial and its split parts
+        pub quotient_bytes: usize,
+    }
+
+    impl MemoryEstimate {
+        /// Returns the sum of every component of the estimate.
+        pub const fn total(&self) -> usize {
+            self.key_bytes
+                + self.witness_bytes
+                + self.coset_bytes
+                + self.quotient_bytes
+        }
+    }
+
+    /// Number of rows of a circuit enabling each gate type, as returned by
+    /// [`ProverKey::gate_histogram`].
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub struct GateHistogram {
+        /// Rows with a non-zero `q_arith` selector
+        pub arithmetic: usize,
+        /// Rows with a non-zero `q_logic` selector
+        pub logic: usize,
+        /// Rows with a non-zero `q_range` selector
+        pub range: usize,
+        /// Rows with a non-zero `q_fixed_group_add` selector
+        pub fixed_group_add: usize,
+        /// Rows with a non-zero `q_variable_group_add` selector
+        pub variable_group_add: usize,
+    }
+
+    /// Selector values of every row of a circuit, as returned by
+    /// [`ProverKey::to_constraint_system`].
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    pub struct ConstraintSystem {
+        /// Selectors of each row, padding rows included
+        pub rows: Vec<ConstraintRow>,
+    }
+
+    /// Selector values of a single row of a circuit.
+    ///
+    /// With `a`, `b`, `c` and `d` the wires of the row and `PI` its public
+    /// input, an arithmetic row is satisfied when `q_arith` is zero or
+    /// `q_m * a * b + q_l * a + q_r * b + q_o * c + q_4 * d + q_c + PI`
+    /// vanishes. The other gates are described in the documentation of
+    /// their widgets.
+    #[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+    pub struct ConstraintRow {
+        /// Multiplication selector
+        pub q_m: BlsScalar,
+        /// Left wire selector
+        pub q_l: BlsScalar,
+        /// Right wire selector
+        pub q_r: BlsScalar,
+        /// Output wire selector
+        pub q_o: BlsScalar,
+        /// Fourth wire selector
+        pub q_4: BlsScalar,
+        /// Constant selector
+        pub q_c: BlsScalar,
+        /// Arithmetic gate selector
+        pub q_arith: BlsScalar,
+        /// Logic gate selector
+        pub q_logic: BlsScalar,
+        /// Range gate selector
+        pub q_range: BlsScalar,
+        /// Fixed base curve addition selector
+        pub q_fixed_group_add: BlsScalar,
+        /// Variable base curve addition selector
+        pub q_variable_group_add: BlsScalar,
+    }
+
+    /// Lists the serialized blocks of a [`ProverKey`], each named after its
+    /// field, in serialization order, and hands the list to `$callback`
+    /// along with `$args`.
+    ///
+    /// The writer, the reader and [`ProverKeyView`] all expand this list, so
+    /// their orders cannot drift apart.
+    macro_rules! ordered_blocks {
+        ($callback:ident!($($args:tt)*)) => {
+            $callback!(
+                ($($args)*),
+                [
+                    q_m: arithmetic.q_m,
+                    q_l: arithmetic.q_l,
+                    q_r: arithmetic.q_r,
+                    q_o: arithmetic.q_o,
+                    q_4: arithmetic.q_4,
+                    q_c: arithmetic.q_c,
+                    q_arith: arithmetic.q_arith,
+                    q_logic: logic.q_logic,
+                    q_range: range.q_range,
+                    q_fixed_group_add: fixed_base.q_fixed_group_add,
+                    q_variable_group_add: variable_base.q_variable_group_add,
+                    left_sigma: permutation.left_sigma,
+                    right_sigma: permutation.right_sigma,
+                    out_sigma: permutation.out_sigma,
+                    fourth_sigma: permutation.fourth_sigma,
+                ],
+                [
+                    linear_evaluations: permutation.linear_evaluations,
+                    v_h_coset_4n: v_h_coset_4n,
+                ]
+            )
+        };
+    }
+
+    /// Borrows every block listed by `ordered_blocks!` from `$key`, either
+    /// shared or mutably, along with its name.
+    macro_rules! borrow_blocks {
+        (
+            ($key:ident, &),
+            [$($name:ident: $($field:ident).+,)+],
+            [$($e_name:ident: $($e_field:ident).+,)+]
+        ) => {
+            (
+                [$((stringify!($name), &$key.$($field).+),)+],
+                [$((stringify!($e_name), &$key.$($e_field).+),)+],
+            )
+        };
+        (
+            ($key:ident, &mut),
+            [$($name:ident: $($field:ident).+,)+],
+            [$($e_name:ident: $($e_field:ident).+,)+]
+        ) => {
+            (
+                [$((stringify!($name), &mut $key.$($field).+),)+],
+                [$((stringify!($e_name), &mut $key.$($e_field).+),)+],
+            )
+        };
+    }
+
+    /// Names of the blocks listed by `ordered_blocks!` that hold a
+    /// [`Polynomial`].
+    macro_rules! poly_names {
+        (
+            (),
+            [$($name:ident: $($field:ident).+,)+],
+            [$($e_name:ident: $($e_field:ident).+,)+]
+        ) => {
+            [$(stringify!($name),)+]
+        };
     }
 
     /// PLONK circuit Proving Key.
@@ -231,302 +996,1222 @@ pub(crate) mod alloc {
         // in their evaluation phase and divide by the quotient
         // polynomial without having to perform IFFT
         pub(crate) v_h_coset_4n: Evaluations,
+        /// Whether the permutation argument is skipped, see
+        /// `ProverKey::disable_permutation_unsound`.
+        #[cfg(feature = "debug-unsound")]
+        pub(crate) permutation_disabled: bool,
+    }
+
+    /// Borrowed blocks of a [`ProverKey`], see [`ProverKey::ordered_fields`].
+    pub(crate) type OrderedFields<'a> = (
+        [(&'static str, &'a (Polynomial, Evaluations)); ProverKey::num_polys()],
+        [(&'static str, &'a Evaluations);
+            ProverKey::num_evals() - ProverKey::num_polys()],
+    );
+
+    /// Mutably borrowed blocks of a [`ProverKey`].
+    type OrderedFieldsMut<'a> = (
+        [(&'static str, &'a mut (Polynomial, Evaluations));
+            ProverKey::num_polys()],
+        [(&'static str, &'a mut Evaluations);
+            ProverKey::num_evals() - ProverKey::num_polys()],
+    );
+
+    /// Domain of the [`Evaluations`] of a [`ProverKey`] being deserialized.
+    #[derive(Clone, Copy)]
+    enum EvalDomain {
+        /// The domain stored along with each [`Evaluations`].
+        Stored,
+        /// The given domain, replacing the stored ones.
+        Replaced(EvaluationDomain),
     }
 
     #[cfg(feature = "alloc")]
     impl ProverKey {
         /// Returns the number of [`Polynomial`]s contained in a ProverKey.
-        const fn num_polys() -> usize {
+        pub(crate) const fn num_polys() -> usize {
             15
         }
 
         /// Returns the number of [`Evaluations`] contained in a ProverKey.
-        const fn num_evals() -> usize {
+        pub(crate) const fn num_evals() -> usize {
             17
         }
 
-        /// Serialises a [`ProverKey`] struct into a Vec of bytes.
-        #[allow(unused_must_use)]
-        pub fn to_var_bytes(&self) -> Vec<u8> {
-            use dusk_bytes::Write;
-            // Fetch size in bytes of each Polynomial
-            let poly_size = self.arithmetic.q_m.0.len() * BlsScalar::SIZE;
-            // Fetch size in bytes of each Evaluations
-            let evals_size = self.arithmetic.q_m.1.evals.len()
-                * BlsScalar::SIZE
-                + EvaluationDomain::SIZE;
-            // Create the vec with the capacity counting the 3 u64's plus the 15
-            // Polys and the 17 Evaluations.
-            let mut bytes = vec![
-                0u8;
-                (Self::num_polys() * poly_size
-                    + evals_size * Self::num_evals()
-                    + 17 * u64::SIZE) as usize
-            ];
-
-            let mut writer = &mut bytes[..];
-            writer.write(&(self.n as u64).to_bytes());
-            // Write Evaluation len in bytes.
-            writer.write(&(evals_size as u64).to_bytes());
-
-            // Arithmetic
-            writer.write(&(self.arithmetic.q_m.0.len() as u64).to_bytes());
-            writer.write(&self.arithmetic.q_m.0.to_var_bytes());
-            writer.write(&self.arithmetic.q_m.1.to_var_bytes());
-
-            writer.write(&(self.arithmetic.q_l.0.len() as u64).to_bytes());
-            writer.write(&self.arithmetic.q_l.0.to_var_bytes());
-            writer.write(&self.arithmetic.q_l.1.to_var_bytes());
-
-            writer.write(&(self.arithmetic.q_r.0.len() as u64).to_bytes());
-            writer.write(&self.arithmetic.q_r.0.to_var_bytes());
-            writer.write(&self.arithmetic.q_r.1.to_var_bytes());
-
-            writer.write(&(self.arithmetic.q_o.0.len() as u64).to_bytes());
-            writer.write(&self.arithmetic.q_o.0.to_var_bytes());
-            writer.write(&self.arithmetic.q_o.1.to_var_bytes());
-
-            writer.write(&(self.arithmetic.q_4.0.len() as u64).to_bytes());
-            writer.write(&self.arithmetic.q_4.0.to_var_bytes());
-            writer.write(&self.arithmetic.q_4.1.to_var_bytes());
-
-            writer.write(&(self.arithmetic.q_c.0.len() as u64).to_bytes());
-            writer.write(&self.arithmetic.q_c.0.to_var_bytes());
-            writer.write(&self.arithmetic.q_c.1.to_var_bytes());
-
-            writer.write(&(self.arithmetic.q_arith.0.len() as u64).to_bytes());
-            writer.write(&self.arithmetic.q_arith.0.to_var_bytes());
-            writer.write(&self.arithmetic.q_arith.1.to_var_bytes());
-
-            // Logic
-            writer.write(&(self.logic.q_logic.0.len() as u64).to_bytes());
-            writer.write(&self.logic.q_logic.0.to_var_bytes());
-            writer.write(&self.logic.q_logic.1.to_var_bytes());
-
-            // Range
-            writer.write(&(self.range.q_range.0.len() as u64).to_bytes());
-            writer.write(&self.range.q_range.0.to_var_bytes());
-            writer.write(&self.range.q_range.1.to_var_bytes());
-
-            // Fixed base multiplication
-            writer.write(
-                &(self.fixed_base.q_fixed_group_add.0.len() as u64).to_bytes(),
-            );
-            writer.write(&self.fixed_base.q_fixed_group_add.0.to_var_bytes());
-            writer.write(&self.fixed_base.q_fixed_group_add.1.to_var_bytes());
+        /// Returns every [`Polynomial`] of the key along with its
+        /// [`Evaluations`] and name, followed by the [`Evaluations`] that have
+        /// no [`Polynomial`] of their own, in serialization order.
+        ///
+        /// The length of the arrays ties [`ProverKey::num_polys`] and
+        /// [`ProverKey::num_evals`] to the fields actually serialized.
+        pub(crate) fn ordered_fields(&self) -> OrderedFields<'_> {
+            ordered_blocks!(borrow_blocks!(self, &))
+        }
 
-            // Variable base addition
-            writer.write(
-                &(self.variable_base.q_variable_group_add.0.len() as u64)
-                    .to_bytes(),
-            );
-            writer.write(
-                &self.variable_base.q_variable_group_add.0.to_var_bytes(),
-            );
-            writer.write(
-                &self.variable_base.q_variable_group_add.1.to_var_bytes(),
-            );
+        /// Mutable counterpart of [`ProverKey::ordered_fields`], used to fill
+        /// a key while reading it.
+        fn ordered_fields_mut(&mut self) -> OrderedFieldsMut<'_> {
+            ordered_blocks!(borrow_blocks!(self, &mut))
+        }
 
-            // Permutation
-            writer.write(
-                &(self.permutation.left_sigma.0.len() as u64).to_bytes(),
-            );
-            writer.write(&self.permutation.left_sigma.0.to_var_bytes());
-            writer.write(&self.permutation.left_sigma.1.to_var_bytes());
+        /// Commits to every selector and sigma polynomial of the key with
+        /// `commit_key`, assembling the matching [`VerifierKey`].
+        ///
+        /// Like in preprocessing, constant selectors, such as the ones of
+        /// gates the circuit never uses, are committed as constants, the
+        /// identity for an empty selector.
+        pub fn to_verifier_key(
+            &self,
+            commit_key: &CommitKey,
+        ) -> Result<VerifierKey, Error> {
+            self.commit_to_verifier_key(commit_key, |poly| {
+                commit_key.commit(poly)
+            })
+        }
 
-            writer.write(
-                &(self.permutation.right_sigma.0.len() as u64).to_bytes(),
-            );
-            writer.write(&self.permutation.right_sigma.0.to_var_bytes());
-            writer.write(&self.permutation.right_sigma.1.to_var_bytes());
+        /// Same as [`ProverKey::to_verifier_key`], but looks every commitment
+        /// up in `cache` first and stores the ones it has to compute.
+        ///
+        /// Keys of circuits sharing selector polynomials, or the same key
+        /// converted again, then skip the matching multi-scalar
+        /// multiplications. The resulting [`VerifierKey`] is identical to the
+        /// one of [`ProverKey::to_verifier_key`].
+        pub fn to_verifier_key_cached(
+            &self,
+            commit_key: &CommitKey,
+            cache: &mut CommitmentCache,
+        ) -> Result<VerifierKey, Error> {
+            self.commit_to_verifier_key(commit_key, |poly| {
+                cache.commit(commit_key, poly)
+            })
+        }
 
-            writer
-                .write(&(self.permutation.out_sigma.0.len() as u64).to_bytes());
-            writer.write(&self.permutation.out_sigma.0.to_var_bytes());
-            writer.write(&self.permutation.out_sigma.1.to_var_bytes());
+        fn commit_to_verifier_key(
+            &self,
+            commit_key: &CommitKey,
+            mut commit: impl FnMut(&Polynomial) -> Result<Commitment, Error>,
+        ) -> Result<VerifierKey, Error> {
+            // Constant selectors are committed with
+            // `CommitKey::commit_selector`, as in `preprocess`, since
+            // `CommitKey::commit` rejects them. Any other error, and any error
+            // on a sigma, is returned.
+            let mut selector = |poly: &Polynomial| {
+                if poly.degree() == 0 {
+                    commit_key.commit_selector(poly)
+                } else {
+                    commit(poly)
+                }
+            };
+            let q_m = selector(&self.arithmetic.q_m.0)?;
+            let q_l = selector(&self.arithmetic.q_l.0)?;
+            let q_r = selector(&self.arithmetic.q_r.0)?;
+            let q_o = selector(&self.arithmetic.q_o.0)?;
+            let q_4 = selector(&self.arithmetic.q_4.0)?;
+            let q_c = selector(&self.arithmetic.q_c.0)?;
+            let q_arith = selector(&self.arithmetic.q_arith.0)?;
+            let q_logic = selector(&self.logic.q_logic.0)?;
+            let q_range = selector(&self.range.q_range.0)?;
+            let q_fixed_group_add =
+                selector(&self.fixed_base.q_fixed_group_add.0)?;
+            let q_variable_group_add =
+                selector(&self.variable_base.q_variable_group_add.0)?;
 
-            writer.write(
-                &(self.permutation.fourth_sigma.0.len() as u64).to_bytes(),
-            );
-            writer.write(&self.permutation.fourth_sigma.0.to_var_bytes());
-            writer.write(&self.permutation.fourth_sigma.1.to_var_bytes());
+            let left_sigma = commit(&self.permutation.left_sigma.0)?;
+            let right_sigma = commit(&self.permutation.right_sigma.0)?;
+            let out_sigma = commit(&self.permutation.out_sigma.0)?;
+            let fourth_sigma = commit(&self.permutation.fourth_sigma.0)?;
 
-            writer.write(&self.permutation.linear_evaluations.to_var_bytes());
+            Ok(VerifierKey::from_polynomial_commitments(
+                self.n,
+                q_m,
+                q_l,
+                q_r,
+                q_o,
+                q_4,
+                q_c,
+                q_arith,
+                q_logic,
+                q_range,
+                q_fixed_group_add,
+                q_variable_group_add,
+                left_sigma,
+                right_sigma,
+                out_sigma,
+                fourth_sigma,
+            ))
+        }
 
-            writer.write(&self.v_h_coset_4n.to_var_bytes());
+        /// Serialises a [`ProverKey`] struct into a Vec of bytes.
+        pub fn to_var_bytes(&self) -> Vec<u8> {
+            let (blocks, _) = self.ordered_fields();
+            // Fetch size in bytes of the Polynomials, which may differ in
+            // length
+            let polys_size: usize = blocks
+                .iter()
+                .map(|(_, (poly, _))| poly.len() * BlsScalar::SIZE)
+                .sum();
+            // Create the vec with the capacity counting the 2 header u64's,
+            // the length prefix of each Polynomial, the Polynomials and the
+            // Evaluations.
+            let mut bytes = Vec::with_capacity(
+                polys_size
+                    + self.evaluations_size() * Self::num_evals()
+                    + (2 + Self::num_polys()) * u64::SIZE,
+            );
+            self.write_bytes(false, |chunk| bytes.extend_from_slice(chunk));
 
             bytes
         }
 
-        /// Deserialises a slice of bytes into a [`ProverKey`].
-        pub fn from_slice(bytes: &[u8]) -> Result<ProverKey, Error> {
-            let mut buffer = bytes;
-            let n = u64::from_reader(&mut buffer)? as usize;
-            let evaluations_size = u64::from_reader(&mut buffer)? as usize;
-            // let domain = crate::fft::EvaluationDomain::new(4 * size)?;
-            // TODO: By creating this we can avoid including the
-            // EvaluationDomain inside Evaluations. See:
-            // dusk-network/plonk#436
-
-            let poly_from_reader =
-                |buf: &mut &[u8]| -> Result<Polynomial, Error> {
-                    let serialized_poly_len =
-                        u64::from_reader(buf)? as usize * BlsScalar::SIZE;
-                    // If the announced len is zero, simply return an empty poly
-                    // and leave the buffer intact.
-                    if serialized_poly_len == 0 {
-                        return Ok(Polynomial { coeffs: vec![] });
-                    }
-                    let (a, b) = buf.split_at(serialized_poly_len);
-                    let poly = Polynomial::from_slice(a);
-                    *buf = b;
+        /// Returns the BLAKE2b hash, with a 32 bytes output and neither key
+        /// nor personalization, of the bytes returned by
+        /// [`ProverKey::to_var_bytes`].
+        ///
+        /// The bytes are hashed as they are produced, so the serialized key
+        /// is never held in memory.
+        pub fn content_hash(&self) -> [u8; 32] {
+            let mut state =
+                blake2b_simd::Params::new().hash_length(32).to_state();
+            self.write_bytes(false, |chunk| {
+                state.update(chunk);
+            });
+
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(state.finalize().as_bytes());
+            hash
+        }
 
-                    poly
-                };
+        /// Size in bytes of each serialized [`Evaluations`].
+        fn evaluations_size(&self) -> usize {
+            self.arithmetic.q_m.1.evals.len() * BlsScalar::SIZE
+                + EvaluationDomain::SIZE
+        }
 
-            let evals_from_reader =
-                |buf: &mut &[u8]| -> Result<Evaluations, Error> {
-                    let (a, b) = buf.split_at(evaluations_size);
-                    let eval = Evaluations::from_slice(a);
-                    *buf = b;
+        /// Feeds the serialization of the key to `sink`, piece by piece,
+        /// omitting the evaluations of empty polynomials when `compact`.
+        fn write_bytes<F: FnMut(&[u8])>(&self, compact: bool, mut sink: F) {
+            let write_evals = |sink: &mut F, evals: &Evaluations| {
+                sink(&evals.domain().to_bytes());
+                evals.evals.iter().for_each(|eval| sink(&eval.to_bytes()));
+            };
 
-                    eval
-                };
+            sink(&(self.n as u64).to_bytes());
+            // Write Evaluation len in bytes.
+            sink(&(self.evaluations_size() as u64).to_bytes());
+
+            let (blocks, trailing) = self.ordered_fields();
+            for (_, (poly, evals)) in blocks {
+                sink(&(poly.len() as u64).to_bytes());
+                poly.coeffs.iter().for_each(|coeff| sink(&coeff.to_bytes()));
+                if !(compact && poly.is_zero()) {
+                    write_evals(&mut sink, evals);
+                }
+            }
+            for (_, evals) in trailing {
+                write_evals(&mut sink, evals);
+            }
+        }
 
-            let q_m_poly = poly_from_reader(&mut buffer)?;
-            let q_m_evals = evals_from_reader(&mut buffer)?;
-            let q_m = (q_m_poly, q_m_evals);
+        /// Serialises a [`ProverKey`] like [`ProverKey::to_var_bytes`], but
+        /// without the evaluations of the polynomials that have no
+        /// coefficients.
+        ///
+        /// The selectors of the gates a circuit never uses are empty, so their
+        /// `4n` evaluations are all zero and can be restored on read. This
+        /// shrinks the keys of circuits that only use a few gate types. The
+        /// output must be read with [`ProverKey::from_slice_compact`].
+        pub fn to_var_bytes_compact(&self) -> Vec<u8> {
+            let mut bytes = Vec::new();
+            self.write_bytes(true, |chunk| bytes.extend_from_slice(chunk));
 
-            let q_l_poly = poly_from_reader(&mut buffer)?;
-            let q_l_evals = evals_from_reader(&mut buffer)?;
-            let q_l = (q_l_poly, q_l_evals);
+            bytes
+        }
 
-            let q_r_poly = poly_from_reader(&mut buffer)?;
-            let q_r_evals = evals_from_reader(&mut buffer)?;
-            let q_r = (q_r_poly, q_r_evals);
+        /// Serialises a [`ProverKey`] like [`ProverKey::to_var_bytes`],
+        /// splitting the output into chunks of at most `max_chunk` bytes.
+        ///
+        /// # Panics
+        /// Panics if `max_chunk` is zero.
+        pub fn to_var_bytes_chunked(&self, max_chunk: usize) -> Vec<Vec<u8>> {
+            self.to_var_bytes()
+                .chunks(max_chunk)
+                .map(|chunk| chunk.to_vec())
+                .collect()
+        }
 
-            let q_o_poly = poly_from_reader(&mut buffer)?;
-            let q_o_evals = evals_from_reader(&mut buffer)?;
-            let q_o = (q_o_poly, q_o_evals);
+        /// Deserialises a [`ProverKey`] from the chunks produced by
+        /// [`ProverKey::to_var_bytes_chunked`], in order.
+        pub fn from_chunks(chunks: &[Vec<u8>]) -> Result<ProverKey, Error> {
+            Self::from_slice(&chunks.concat())
+        }
 
-            let q_4_poly = poly_from_reader(&mut buffer)?;
-            let q_4_evals = evals_from_reader(&mut buffer)?;
-            let q_4 = (q_4_poly, q_4_evals);
+        /// Deserialises a slice of bytes into a [`ProverKey`], checking it with
+        /// [`ProverKey::validate_degrees`].
+        pub fn from_slice(bytes: &[u8]) -> Result<ProverKey, Error> {
+            Self::from_slice_and_domain(bytes, EvalDomain::Stored, false)
+        }
 
-            let q_c_poly = poly_from_reader(&mut buffer)?;
-            let q_c_evals = evals_from_reader(&mut buffer)?;
-            let q_c = (q_c_poly, q_c_evals);
+        /// Deserialises the output of [`ProverKey::to_var_bytes_compact`]
+        /// into a [`ProverKey`], restoring the omitted evaluations as zeros.
+        pub fn from_slice_compact(bytes: &[u8]) -> Result<ProverKey, Error> {
+            Self::from_slice_and_domain(bytes, EvalDomain::Stored, true)
+        }
 
-            let q_arith_poly = poly_from_reader(&mut buffer)?;
-            let q_arith_evals = evals_from_reader(&mut buffer)?;
-            let q_arith = (q_arith_poly, q_arith_evals);
+        /// Deserialises a slice of bytes into a [`ProverKey`] whose
+        /// [`Evaluations`] are all taken over `domain`, instead of the
+        /// domains stored along with them.
+        ///
+        /// This is meant for experimenting with domain layouts other than the
+        /// `4n` coset used by the prover. Returns
+        /// [`Error::MismatchedEvalDomainSize`] if the serialized evaluations
+        /// do not have one value per element of `domain`.
+        pub fn from_slice_with_domain(
+            bytes: &[u8],
+            domain: EvaluationDomain,
+        ) -> Result<ProverKey, Error> {
+            Self::from_slice_and_domain(
+                bytes,
+                EvalDomain::Replaced(domain),
+                false,
+            )
+        }
 
-            let q_logic_poly = poly_from_reader(&mut buffer)?;
-            let q_logic_evals = evals_from_reader(&mut buffer)?;
-            let q_logic = (q_logic_poly, q_logic_evals);
+        fn from_slice_and_domain(
+            bytes: &[u8],
+            domain: EvalDomain,
+            compact: bool,
+        ) -> Result<ProverKey, Error> {
+            let mut buffer = bytes;
+            let n = circuit_size_from_u64(u64::from_reader(&mut buffer)?)?;
+            let evaluations_size =
+                Self::evaluations_size_from_reader(&mut buffer)?;
+
+            // Even with empty polynomials, the key must contain the length
+            // prefix of each of them and all of the Evaluations, except for
+            // the ones of empty polynomials in a compact key.
+            let evals = match compact {
+                true => Self::num_evals() - Self::num_polys(),
+                false => Self::num_evals(),
+            };
+            let expected = evaluations_size
+                .saturating_mul(evals)
+                .saturating_add(Self::num_polys() * u64::SIZE);
+            if buffer.len() < expected {
+                return Err(Error::TruncatedProverKey {
+                    expected,
+                    available: buffer.len(),
+                });
+            }
 
-            let q_range_poly = poly_from_reader(&mut buffer)?;
-            let q_range_evals = evals_from_reader(&mut buffer)?;
-            let q_range = (q_range_poly, q_range_evals);
+            // let domain = crate::fft::EvaluationDomain::new(4 * size)?;
+            // TODO: By creating this we can avoid including the
+            // EvaluationDomain inside Evaluations. See:
+            // dusk-network/plonk#436
 
-            let q_fixed_group_add_poly = poly_from_reader(&mut buffer)?;
-            let q_fixed_group_add_evals = evals_from_reader(&mut buffer)?;
-            let q_fixed_group_add =
-                (q_fixed_group_add_poly, q_fixed_group_add_evals);
+            let poly_from_reader = Self::poly_from_reader;
+            if let EvalDomain::Replaced(domain) = domain {
+                let evaluations = (evaluations_size - EvaluationDomain::SIZE)
+                    / BlsScalar::SIZE;
+                if evaluations != domain.size() {
+                    return Err(Error::MismatchedEvalDomainSize {
+                        domain: domain.size(),
+                        evaluations,
+                    });
+                }
+            }
+
+            let evals_from_reader = |buf: &mut &[u8]| {
+                let evals = Self::evals_from_reader(buf, evaluations_size)?;
+                match domain {
+                    EvalDomain::Stored => Ok(evals),
+                    EvalDomain::Replaced(domain) => Ok(
+                        Evaluations::from_vec_and_domain(evals.evals, domain),
+                    ),
+                }
+            };
 
-            let q_variable_group_add_poly = poly_from_reader(&mut buffer)?;
-            let q_variable_group_add_evals = evals_from_reader(&mut buffer)?;
-            let q_variable_group_add =
-                (q_variable_group_add_poly, q_variable_group_add_evals);
+            let mut blocks = Vec::with_capacity(Self::num_polys());
+            for _ in 0..Self::num_polys() {
+                let poly = poly_from_reader(&mut buffer)?;
+                let evals = match compact && poly.is_zero() {
+                    true => None,
+                    false => Some(evals_from_reader(&mut buffer)?),
+                };
+                blocks.push((poly, evals));
+            }
+            let mut trailing =
+                Vec::with_capacity(Self::num_evals() - Self::num_polys());
+            for _ in Self::num_polys()..Self::num_evals() {
+                trailing.push(evals_from_reader(&mut buffer)?);
+            }
+
+            // Evaluations omitted from a compact key are all zero, over the
+            // same domain as the others.
+            let zeros = Evaluations::from_vec_and_domain(
+                vec![BlsScalar::zero(); trailing[0].evals.len()],
+                *trailing[0].domain(),
+            );
+            let blocks = blocks.into_iter().map(|(poly, evals)| {
+                (poly, evals.unwrap_or_else(|| zeros.clone()))
+            });
+
+            // Every field is overwritten below, following the order used by
+            // the writer.
+            let empty =
+                Evaluations::from_vec_and_domain(vec![], *trailing[0].domain());
+            let empty = (Polynomial { coeffs: vec![] }, empty);
+            let mut prover_key = ProverKey {
+                n,
+                arithmetic: arithmetic::ProverKey {
+                    q_m: empty.clone(),
+                    q_l: empty.clone(),
+                    q_r: empty.clone(),
+                    q_o: empty.clone(),
+                    q_c: empty.clone(),
+                    q_4: empty.clone(),
+                    q_arith: empty.clone(),
+                },
+                logic: logic::ProverKey {
+                    q_logic: empty.clone(),
+                    q_c: empty.clone(),
+                },
+                range: range::ProverKey {
+                    q_range: empty.clone(),
+                },
+                fixed_base: ecc::scalar_mul::fixed_base::ProverKey {
+                    q_l: empty.clone(),
+                    q_r: empty.clone(),
+                    q_c: empty.clone(),
+                    q_fixed_group_add: empty.clone(),
+                },
+                variable_base: ecc::curve_addition::ProverKey {
+                    q_variable_group_add: empty.clone(),
+                },
+                permutation: permutation::ProverKey {
+                    left_sigma: empty.clone(),
+                    right_sigma: empty.clone(),
+                    out_sigma: empty.clone(),
+                    fourth_sigma: empty.clone(),
+                    linear_evaluations: empty.1.clone(),
+                },
+                v_h_coset_4n: empty.1,
+                #[cfg(feature = "debug-unsound")]
+                permutation_disabled: false,
+            };
 
-            let left_sigma_poly = poly_from_reader(&mut buffer)?;
-            let left_sigma_evals = evals_from_reader(&mut buffer)?;
-            let left_sigma = (left_sigma_poly, left_sigma_evals);
+            let (block_fields, trailing_fields) =
+                prover_key.ordered_fields_mut();
+            for (block, (_, field)) in blocks.into_iter().zip(block_fields) {
+                *field = block;
+            }
+            for (evals, (_, field)) in trailing.into_iter().zip(trailing_fields)
+            {
+                *field = evals;
+            }
+
+            // Selectors shared between widgets are only serialized once.
+            prover_key.logic.q_c = prover_key.arithmetic.q_c.clone();
+            prover_key.fixed_base.q_l = prover_key.arithmetic.q_l.clone();
+            prover_key.fixed_base.q_r = prover_key.arithmetic.q_r.clone();
+            prover_key.fixed_base.q_c = prover_key.arithmetic.q_c.clone();
+
+            // Keys read over a custom domain are not meant for the prover, so
+            // their evaluations need not hold `4n` values.
+            if !matches!(domain, EvalDomain::Replaced(_)) {
+                prover_key.validate_degrees()?;
+            }
 
-            let right_sigma_poly = poly_from_reader(&mut buffer)?;
-            let right_sigma_evals = evals_from_reader(&mut buffer)?;
-            let right_sigma = (right_sigma_poly, right_sigma_evals);
+            Ok(prover_key)
+        }
 
-            let out_sigma_poly = poly_from_reader(&mut buffer)?;
-            let out_sigma_evals = evals_from_reader(&mut buffer)?;
-            let out_sigma = (out_sigma_poly, out_sigma_evals);
+        /// Reads the size in bytes of each [`Evaluations`] block of a
+        /// serialized key, which holds its domain followed by a whole number
+        /// of scalars.
+        fn evaluations_size_from_reader(
+            buf: &mut &[u8],
+        ) -> Result<usize, Error> {
+            let evaluations_size = u64::from_reader(buf)? as usize;
+            if evaluations_size < EvaluationDomain::SIZE
+                || (evaluations_size - EvaluationDomain::SIZE) % BlsScalar::SIZE
+                    != 0
+            {
+                return Err(DuskBytesError::InvalidData.into());
+            }
+
+            Ok(evaluations_size)
+        }
 
-            let fourth_sigma_poly = poly_from_reader(&mut buffer)?;
-            let fourth_sigma_evals = evals_from_reader(&mut buffer)?;
-            let fourth_sigma = (fourth_sigma_poly, fourth_sigma_evals);
+        /// Reads a length-prefixed [`Polynomial`] of a serialized key.
+        fn poly_from_reader(buf: &mut &[u8]) -> Result<Polynomial, Error> {
+            let serialized_poly_len = (u64::from_reader(buf)? as usize)
+                .saturating_mul(BlsScalar::SIZE);
+            // If the announced len is zero, simply return an empty poly
+            // and leave the buffer intact.
+            if serialized_poly_len == 0 {
+                return Ok(Polynomial { coeffs: vec![] });
+            }
+            if buf.len() < serialized_poly_len {
+                return Err(Error::TruncatedProverKey {
+                    expected: serialized_poly_len,
+                    available: buf.len(),
+                });
+            }
+            let (a, b) = buf.split_at(serialized_poly_len);
+            let poly = Polynomial::from_slice(a);
+            *buf = b;
+
+            poly
+        }
 
-            let perm_linear_evaluations = evals_from_reader(&mut buffer)?;
+        /// Reads an [`Evaluations`] block of `evaluations_size` bytes of a
+        /// serialized key.
+        fn evals_from_reader(
+            buf: &mut &[u8],
+            evaluations_size: usize,
+        ) -> Result<Evaluations, Error> {
+            if buf.len() < evaluations_size {
+                return Err(Error::TruncatedProverKey {
+                    expected: evaluations_size,
+                    available: buf.len(),
+                });
+            }
+            let (a, b) = buf.split_at(evaluations_size);
+            let eval = Evaluations::from_slice(a);
+            *buf = b;
+
+            eval
+        }
 
-            let v_h_coset_4n = evals_from_reader(&mut buffer)?;
+        /// Serialises the permutation sub-key of a [`ProverKey`]: the four
+        /// sigma polynomials with their evaluations and the linear
+        /// evaluations, preceded by the circuit size and the size of each
+        /// [`Evaluations`] block.
+        ///
+        /// The output can be patched into a key of the same size with
+        /// [`ProverKey::set_permutation_from_bytes`].
+        pub fn permutation_to_bytes(&self) -> Vec<u8> {
+            let perm = &self.permutation;
+            let evals_size = perm.linear_evaluations.evals.len()
+                * BlsScalar::SIZE
+                + EvaluationDomain::SIZE;
 
-            let arithmetic = arithmetic::ProverKey {
-                q_m,
-                q_l: q_l.clone(),
-                q_r: q_r.clone(),
-                q_o,
-                q_c: q_c.clone(),
-                q_4,
-                q_arith,
-            };
+            let mut bytes = Vec::with_capacity(
+                4 * (perm.left_sigma.0.len() * BlsScalar::SIZE + u64::SIZE)
+                    + 5 * evals_size
+                    + 2 * u64::SIZE,
+            );
+            bytes.extend_from_slice(&(self.n as u64).to_bytes());
+            bytes.extend_from_slice(&(evals_size as u64).to_bytes());
+
+            let sigmas = [
+                &perm.left_sigma,
+                &perm.right_sigma,
+                &perm.out_sigma,
+                &perm.fourth_sigma,
+            ];
+            for (poly, evals) in sigmas {
+                bytes.extend_from_slice(&(poly.len() as u64).to_bytes());
+                bytes.extend(poly.to_var_bytes());
+                bytes.extend(evals.to_var_bytes());
+            }
+            bytes.extend(perm.linear_evaluations.to_var_bytes());
 
-            let logic = logic::ProverKey {
-                q_logic,
-                q_c: q_c.clone(),
-            };
+            bytes
+        }
 
-            let range = range::ProverKey { q_range };
+        /// Replaces the permutation sub-key of `self` with the one serialized
+        /// by [`ProverKey::permutation_to_bytes`], leaving the selectors
+        /// untouched.
+        ///
+        /// Returns [`Error::MismatchedCircuitSize`] if the sub-key was built
+        /// for a circuit of another size. On error, `self` is not modified.
+        pub fn set_permutation_from_bytes(
+            &mut self,
+            bytes: &[u8],
+        ) -> Result<(), Error> {
+            let mut buffer = bytes;
+            let n = circuit_size_from_u64(u64::from_reader(&mut buffer)?)?;
+            if n != self.n {
+                return Err(Error::MismatchedCircuitSize {
+                    expected: self.n,
+                    found: n,
+                });
+            }
+
+            let evaluations_size =
+                Self::evaluations_size_from_reader(&mut buffer)?;
+            let own_evaluations_size = self.v_h_coset_4n.evals.len()
+                * BlsScalar::SIZE
+                + EvaluationDomain::SIZE;
+            if evaluations_size != own_evaluations_size {
+                return Err(DuskBytesError::InvalidData.into());
+            }
+
+            let mut sigma_from_reader =
+                || -> Result<(Polynomial, Evaluations), Error> {
+                    let poly = Self::poly_from_reader(&mut buffer)?;
+                    let evals =
+                        Self::evals_from_reader(&mut buffer, evaluations_size)?;
+                    Ok((poly, evals))
+                };
 
-            let fixed_base = ecc::scalar_mul::fixed_base::ProverKey {
-                q_l,
-                q_r,
-                q_c,
-                q_fixed_group_add,
-            };
+            let left_sigma = sigma_from_reader()?;
+            let right_sigma = sigma_from_reader()?;
+            let out_sigma = sigma_from_reader()?;
+            let fourth_sigma = sigma_from_reader()?;
+            let linear_evaluations =
+                Self::evals_from_reader(&mut buffer, evaluations_size)?;
 
-            let permutation = permutation::ProverKey {
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut self.permutation);
+            self.permutation = permutation::ProverKey {
                 left_sigma,
                 right_sigma,
                 out_sigma,
                 fourth_sigma,
-                linear_evaluations: perm_linear_evaluations,
+                linear_evaluations,
             };
 
-            let variable_base = ecc::curve_addition::ProverKey {
-                q_variable_group_add,
-            };
+            Ok(())
+        }
 
-            let prover_key = ProverKey {
-                n,
-                arithmetic,
-                logic,
-                range,
-                fixed_base,
-                variable_base,
-                permutation,
-                v_h_coset_4n,
-            };
+        /// Returns the coefficients of the multiplication selector.
+        pub fn q_m_polynomial(&self) -> &Polynomial {
+            &self.arithmetic.q_m.0
+        }
 
-            Ok(prover_key)
+        /// Returns the coefficients of the left wire selector.
+        pub fn q_l_polynomial(&self) -> &Polynomial {
+            &self.arithmetic.q_l.0
         }
 
-        pub(crate) fn v_h_coset_4n(&self) -> &Evaluations {
-            &self.v_h_coset_4n
+        /// Returns the coefficients of the right wire selector.
+        pub fn q_r_polynomial(&self) -> &Polynomial {
+            &self.arithmetic.q_r.0
         }
-    }
-}
 
-#[cfg(feature = "alloc")]
-#[cfg(test)]
-mod test {
-    use super::alloc::ProverKey;
-    use super::*;
-    use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
-    use ::alloc::vec::Vec;
-    use dusk_bls12_381::BlsScalar;
-    use rand_core::OsRng;
+        /// Returns the coefficients of the output wire selector.
+        pub fn q_o_polynomial(&self) -> &Polynomial {
+            &self.arithmetic.q_o.0
+        }
+
+        /// Returns the coefficients of the fourth wire selector.
+        pub fn q_4_polynomial(&self) -> &Polynomial {
+            &self.arithmetic.q_4.0
+        }
+
+        /// Returns the coefficients of the constant selector.
+        pub fn q_c_polynomial(&self) -> &Polynomial {
+            &self.arithmetic.q_c.0
+        }
+
+        /// Returns the coefficients of the arithmetic gate selector.
+        pub fn q_arith_polynomial(&self) -> &Polynomial {
+            &self.arithmetic.q_arith.0
+        }
+
+        /// Returns the coefficients of the logic gate selector.
+        pub fn q_logic_polynomial(&self) -> &Polynomial {
+            &self.logic.q_logic.0
+        }
+
+        /// Returns the coefficients of the range gate selector.
+        pub fn q_range_polynomial(&self) -> &Polynomial {
+            &self.range.q_range.0
+        }
+
+        /// Returns the coefficients of the fixed base curve addition selector.
+        pub fn q_fixed_group_add_polynomial(&self) -> &Polynomial {
+            &self.fixed_base.q_fixed_group_add.0
+        }
+
+        /// Returns the coefficients of the variable base curve addition
+        /// selector.
+        pub fn q_variable_group_add_polynomial(&self) -> &Polynomial {
+            &self.variable_base.q_variable_group_add.0
+        }
+
+        /// Returns the coefficients of the left sigma of the permutation.
+        pub fn left_sigma_polynomial(&self) -> &Polynomial {
+            &self.permutation.left_sigma.0
+        }
+
+        /// Returns the coefficients of the right sigma of the permutation.
+        pub fn right_sigma_polynomial(&self) -> &Polynomial {
+            &self.permutation.right_sigma.0
+        }
+
+        /// Returns the coefficients of the out sigma of the permutation.
+        pub fn out_sigma_polynomial(&self) -> &Polynomial {
+            &self.permutation.out_sigma.0
+        }
+
+        /// Returns the coefficients of the fourth sigma of the permutation.
+        pub fn fourth_sigma_polynomial(&self) -> &Polynomial {
+            &self.permutation.fourth_sigma.0
+        }
+
+        /// Returns the evaluations of the identity polynomial `X` over the
+        /// `4n` coset, used by the permutation argument.
+        pub fn linear_evaluations(&self) -> &Evaluations {
+            &self.permutation.linear_evaluations
+        }
+
+        /// Lists the name, degree and number of non-zero coefficients of each
+        /// selector and sigma polynomial, in serialization order.
+        ///
+        /// A selector with no non-zero coefficient belongs to a widget the
+        /// circuit does not use.
+        pub fn report(&self) -> Vec<(&'static str, usize, usize)> {
+            let (blocks, _) = self.ordered_fields();
+
+            blocks
+                .iter()
+                .map(|(name, (poly, _))| {
+                    (*name, poly.degree(), poly.nonzero_count())
+                })
+                .collect()
+        }
+
+        /// Removes the zero coefficients of the highest degrees of every
+        /// selector and sigma polynomial, shrinking the serialized key.
+        ///
+        /// A trimmed key is equivalent to the untrimmed one for proving: see
+        /// [`Polynomial::trim_trailing_zeros`]. The serialization prefixes
+        /// each polynomial with its own length, so they need not agree.
+        pub fn trim_trailing_zeros(&mut self) {
+            let (blocks, _) = self.ordered_fields_mut();
+            for (_, (poly, _)) in blocks {
+                poly.trim_trailing_zeros();
+            }
+
+            // Selectors shared between widgets are not listed twice
+            self.logic.q_c.0.trim_trailing_zeros();
+            self.fixed_base.q_l.0.trim_trailing_zeros();
+            self.fixed_base.q_r.0.trim_trailing_zeros();
+            self.fixed_base.q_c.0.trim_trailing_zeros();
+        }
+
+        /// Counts the rows of the circuit enabling each gate type, by
+        /// evaluating the gate selectors over the `n`-domain.
+        pub fn gate_histogram(&self) -> Result<GateHistogram, Error> {
+            let domain = EvaluationDomain::new(self.n)?;
+            let active = |poly: &Polynomial| {
+                poly.evaluate_over_domain(&domain)
+                    .evals
+                    .iter()
+                    .filter(|eval| **eval != BlsScalar::zero())
+                    .count()
+            };
+
+            Ok(GateHistogram {
+                arithmetic: active(&self.arithmetic.q_arith.0),
+                logic: active(&self.logic.q_logic.0),
+                range: active(&self.range.q_range.0),
+                fixed_group_add: active(&self.fixed_base.q_fixed_group_add.0),
+                variable_group_add: active(
+                    &self.variable_base.q_variable_group_add.0,
+                ),
+            })
+        }
+
+        /// Recovers the selectors of every row of the circuit, by evaluating
+        /// the selector polynomials over the `n`-domain, so the constraints
+        /// can be checked again by an external solver.
+        pub fn to_constraint_system(&self) -> Result<ConstraintSystem, Error> {
+            let domain = EvaluationDomain::new(self.n)?;
+            let evals = |(poly, _): &(Polynomial, Evaluations)| {
+                poly.evaluate_over_domain(&domain).evals
+            };
+
+            let q_m = evals(&self.arithmetic.q_m);
+            let q_l = evals(&self.arithmetic.q_l);
+            let q_r = evals(&self.arithmetic.q_r);
+            let q_o = evals(&self.arithmetic.q_o);
+            let q_4 = evals(&self.arithmetic.q_4);
+            let q_c = evals(&self.arithmetic.q_c);
+            let q_arith = evals(&self.arithmetic.q_arith);
+            let q_logic = evals(&self.logic.q_logic);
+            let q_range = evals(&self.range.q_range);
+            let q_fixed_group_add = evals(&self.fixed_base.q_fixed_group_add);
+            let q_variable_group_add =
+                evals(&self.variable_base.q_variable_group_add);
+
+            let rows = (0..domain.size())
+                .map(|i| ConstraintRow {
+                    q_m: q_m[i],
+                    q_l: q_l[i],
+                    q_r: q_r[i],
+                    q_o: q_o[i],
+                    q_4: q_4[i],
+                    q_c: q_c[i],
+                    q_arith: q_arith[i],
+                    q_logic: q_logic[i],
+                    q_range: q_range[i],
+                    q_fixed_group_add: q_fixed_group_add[i],
+                    q_variable_group_add: q_variable_group_add[i],
+                })
+                .collect();
+
+            Ok(ConstraintSystem { rows })
+        }
+
+        /// Returns the minimum degree of the commit key needed to prove with
+        /// this [`ProverKey`], such as the one passed to
+        /// [`PublicParameters::trim`].
+        ///
+        /// This is `n - 1`: the wire, permutation and opening polynomials all
+        /// have fewer than `n` coefficients, and the quotient polynomial is
+        /// committed in four chunks of `n` coefficients, so no proving
+        /// polynomial exceeds the degree of the selectors.
+        ///
+        /// [`PublicParameters::trim`]:
+        /// crate::commitment_scheme::kzg10::PublicParameters::trim
+        pub fn required_srs_degree(&self) -> usize {
+            let (blocks, _) = self.ordered_fields();
+            blocks
+                .iter()
+                .map(|(_, (poly, _))| poly.degree())
+                .fold(self.n.saturating_sub(1), usize::max)
+        }
+
+        /// Returns an estimate, in bytes, of the memory needed to compute a
+        /// proof with this [`ProverKey`].
+        ///
+        /// The estimate only accounts for the buffers whose size depends on
+        /// the circuit size, so the peak usage is expected to stay within a
+        /// factor of two of [`MemoryEstimate::total`].
+        pub fn memory_estimate(&self) -> MemoryEstimate {
+            let n = self.n * BlsScalar::SIZE;
+
+            MemoryEstimate {
+                key_bytes: Self::num_polys() * n + Self::num_evals() * 4 * n,
+                // 4 wire value vectors, 4 wire polynomials, the permutation
+                // polynomial and the public inputs polynomial
+                witness_bytes: 10 * n,
+                // 5 witness polynomials and 2 quotient numerator terms
+                coset_bytes: 7 * 4 * n,
+                // Quotient evaluations, its interpolation and its 4 parts
+                quotient_bytes: 3 * 4 * n,
+            }
+        }
+
+        /// Returns the total of [`ProverKey::memory_estimate`], in bytes.
+        pub fn estimated_proving_memory(&self) -> usize {
+            self.memory_estimate().total()
+        }
+
+        /// Returns the fields in which `self` and `other` differ, comparing
+        /// polynomial coefficients and evaluation values.
+        ///
+        /// Fields are reported in the order used by
+        /// [`ProverKey::to_var_bytes`].
+        pub fn diff(&self, other: &Self) -> Vec<ProverKeyField> {
+            use ProverKeyField::*;
+
+            let mut fields = Vec::new();
+            if self.n != other.n {
+                fields.push(N);
+            }
+
+            let selectors = [
+                (QM, &self.arithmetic.q_m, &other.arithmetic.q_m),
+                (QL, &self.arithmetic.q_l, &other.arithmetic.q_l),
+                (QR, &self.arithmetic.q_r, &other.arithmetic.q_r),
+                (QO, &self.arithmetic.q_o, &other.arithmetic.q_o),
+                (Q4, &self.arithmetic.q_4, &other.arithmetic.q_4),
+                (QC, &self.arithmetic.q_c, &other.arithmetic.q_c),
+                (QArith, &self.arithmetic.q_arith, &other.arithmetic.q_arith),
+                (QLogic, &self.logic.q_logic, &other.logic.q_logic),
+                (QRange, &self.range.q_range, &other.range.q_range),
+                (
+                    QFixedGroupAdd,
+                    &self.fixed_base.q_fixed_group_add,
+                    &other.fixed_base.q_fixed_group_add,
+                ),
+                (
+                    QVariableGroupAdd,
+                    &self.variable_base.q_variable_group_add,
+                    &other.variable_base.q_variable_group_add,
+                ),
+                (
+                    LeftSigma,
+                    &self.permutation.left_sigma,
+                    &other.permutation.left_sigma,
+                ),
+                (
+                    RightSigma,
+                    &self.permutation.right_sigma,
+                    &other.permutation.right_sigma,
+                ),
+                (
+                    OutSigma,
+                    &self.permutation.out_sigma,
+                    &other.permutation.out_sigma,
+                ),
+                (
+                    FourthSigma,
+                    &self.permutation.fourth_sigma,
+                    &other.permutation.fourth_sigma,
+                ),
+            ];
+            fields.extend(
+                selectors
+                    .iter()
+                    .filter(|(_, a, b)| a != b)
+                    .map(|(field, _, _)| *field),
+            );
+
+            if self.permutation.linear_evaluations
+                != other.permutation.linear_evaluations
+            {
+                fields.push(LinearEvaluations);
+            }
+            if self.v_h_coset_4n != other.v_h_coset_4n {
+                fields.push(VHCoset);
+            }
+
+            fields
+        }
+
+        /// Checks that the selectors shared between widgets are identical in
+        /// each of them: `q_c` in the arithmetic, logic and fixed base keys,
+        /// `q_l` and `q_r` in the arithmetic and fixed base keys.
+        ///
+        /// Keys built by preprocessing or deserialization always pass, but a
+        /// hand-constructed key might not, in which case
+        /// [`Error::InconsistentSharedSelector`] names the first mismatch.
+        pub fn validate_shared_selectors(&self) -> Result<(), Error> {
+            let shared = [
+                ("q_c", &self.arithmetic.q_c, &self.logic.q_c),
+                ("q_c", &self.arithmetic.q_c, &self.fixed_base.q_c),
+                ("q_l", &self.arithmetic.q_l, &self.fixed_base.q_l),
+                ("q_r", &self.arithmetic.q_r, &self.fixed_base.q_r),
+            ];
+            for (selector, a, b) in shared {
+                if a != b {
+                    return Err(Error::InconsistentSharedSelector { selector });
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Checks that every selector and sigma polynomial has a degree
+        /// lower than the circuit size, and that the permutation linear
+        /// evaluations and the vanishing polynomial evaluations hold `4n`
+        /// values.
+        ///
+        /// Returns [`Error::SelectorDegreeTooHigh`] or
+        /// [`Error::MismatchedEvalDomainSize`] for the first offending field.
+        pub fn validate_degrees(&self) -> Result<(), Error> {
+            let (blocks, trailing) = self.ordered_fields();
+            for (name, (poly, _)) in blocks {
+                let degree = poly.degree();
+                if degree >= self.n {
+                    return Err(Error::SelectorDegreeTooHigh {
+                        name,
+                        degree,
+                        n: self.n,
+                    });
+                }
+            }
+            for (_, evals) in trailing {
+                if evals.evals.len() != 4 * self.n {
+                    return Err(Error::MismatchedEvalDomainSize {
+                        domain: 4 * self.n,
+                        evaluations: evals.evals.len(),
+                    });
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Checks that the sigma polynomials encode a permutation of the
+        /// `4n` wire positions, evaluating them over the domain of size `n`.
+        ///
+        /// Keys built by preprocessing always pass, while a hand-constructed
+        /// or corrupted key whose sigmas are not a bijection would silently
+        /// break the copy constraints and returns
+        /// [`Error::InvalidPermutation`].
+        pub fn validate_permutation(&self) -> Result<(), Error> {
+            let domain = EvaluationDomain::new(self.n)?;
+            self.permutation.validate_permutation(&domain)
+        }
+
+        /// Returns, for each of the `4n` wire positions, the index of the
+        /// position the sigma polynomials map it to: row `j` of the left,
+        /// right, output and fourth wires has index `j`, `n + j`, `2n + j`
+        /// and `3n + j` respectively.
+        ///
+        /// Positions mapped to themselves are the wires without copy
+        /// constraints, while the cycles of the map are the sets of wires
+        /// holding the same variable. Returns [`Error::InvalidPermutation`]
+        /// if a sigma evaluates to a value that is not a wire position.
+        pub fn permutation_map(&self) -> Result<Vec<usize>, Error> {
+            let domain = EvaluationDomain::new(self.n)?;
+            self.permutation.permutation_map(&domain)
+        }
+
+        /// Restricts the key to the first `new_n` rows of its circuit, such
+        /// as a circuit preprocessed with more padding than it needs.
+        ///
+        /// Every selector is interpolated back from its first `new_n`
+        /// evaluations and the sigmas have their wire positions remapped to
+        /// the smaller domain, so the result is the key that preprocessing
+        /// the circuit over `new_n` rows would give. Returns
+        /// [`Error::InvalidTruncationSize`] if `new_n` is not a power of two
+        /// no larger than `n`, and [`Error::TruncatedSupport`] if a selector
+        /// is not zero beyond the first `new_n` rows or a copy constraint
+        /// crosses them.
+        pub fn truncate_to(&self, new_n: usize) -> Result<ProverKey, Error> {
+            if new_n > self.n || !new_n.is_power_of_two() {
+                return Err(Error::InvalidTruncationSize { n: self.n, new_n });
+            }
+            let domain = EvaluationDomain::new(self.n)?;
+            let new_domain = EvaluationDomain::new(new_n)?;
+            let domain_4n = EvaluationDomain::new(4 * new_n)?;
+
+            let truncate = |name, poly: &Polynomial| {
+                let mut evals = domain.fft(poly);
+                if evals[new_n..].iter().any(|e| e != &BlsScalar::zero()) {
+                    return Err(Error::TruncatedSupport { name, new_n });
+                }
+                evals.truncate(new_n);
+                Ok(Polynomial::from_coefficients_vec(new_domain.ifft(&evals)))
+            };
+            let with_evals = |poly: Polynomial| {
+                let evals = domain_4n.coset_fft(&poly);
+                (poly, Evaluations::from_vec_and_domain(evals, domain_4n))
+            };
+
+            let mut key = self.clone();
+            key.n = new_n;
+
+            let arithmetic = &mut key.arithmetic;
+            arithmetic.q_m = with_evals(truncate("q_m", &arithmetic.q_m.0)?);
+            arithmetic.q_l = with_evals(truncate("q_l", &arithmetic.q_l.0)?);
+            arithmetic.q_r = with_evals(truncate("q_r", &arithmetic.q_r.0)?);
+            arithmetic.q_o = with_evals(truncate("q_o", &arithmetic.q_o.0)?);
+            arithmetic.q_4 = with_evals(truncate("q_4", &arithmetic.q_4.0)?);
+            arithmetic.q_c = with_evals(truncate("q_c", &arithmetic.q_c.0)?);
+            arithmetic.q_arith =
+                with_evals(truncate("q_arith", &arithmetic.q_arith.0)?);
+            key.logic.q_logic =
+                with_evals(truncate("q_logic", &key.logic.q_logic.0)?);
+            key.range.q_range =
+                with_evals(truncate("q_range", &key.range.q_range.0)?);
+            key.fixed_base.q_fixed_group_add = with_evals(truncate(
+                "q_fixed_group_add",
+                &key.fixed_base.q_fixed_group_add.0,
+            )?);
+            key.variable_base.q_variable_group_add = with_evals(truncate(
+                "q_variable_group_add",
+                &key.variable_base.q_variable_group_add.0,
+            )?);
+
+            let [left_sigma, right_sigma, out_sigma, fourth_sigma] =
+                self.permutation.truncated_sigmas(&domain, &new_domain)?;
+            key.permutation.left_sigma = with_evals(left_sigma);
+            key.permutation.right_sigma = with_evals(right_sigma);
+            key.permutation.out_sigma = with_evals(out_sigma);
+            key.permutation.fourth_sigma = with_evals(fourth_sigma);
+
+            key.permutation.linear_evaluations =
+                Evaluations::from_vec_and_domain(
+                    domain_4n.coset_fft(&[BlsScalar::zero(), BlsScalar::one()]),
+                    domain_4n,
+                );
+            key.v_h_coset_4n =
+                domain_4n.compute_vanishing_poly_over_coset(new_n as u64);
+
+            // Selectors shared between widgets are only truncated once.
+            key.logic.q_c = key.arithmetic.q_c.clone();
+            key.fixed_base.q_l = key.arithmetic.q_l.clone();
+            key.fixed_base.q_r = key.arithmetic.q_r.clone();
+            key.fixed_base.q_c = key.arithmetic.q_c.clone();
+
+            Ok(key)
+        }
+
+        /// Returns `false` if the permutation argument was disabled with
+        /// `ProverKey::disable_permutation_unsound`.
+        #[cfg(feature = "debug-unsound")]
+        pub(crate) fn permutation_enabled(&self) -> bool {
+            !self.permutation_disabled
+        }
+
+        /// Returns `true`, since the permutation argument can only be
+        /// disabled with the `debug-unsound` feature.
+        #[cfg(not(feature = "debug-unsound"))]
+        pub(crate) fn permutation_enabled(&self) -> bool {
+            true
+        }
+
+        /// Leaves the permutation argument out of the proofs made with this
+        /// key, so that the gates of a circuit can be tested without its copy
+        /// constraints. The matching [`VerifierKey`] must be flagged with
+        /// `VerifierKey::disable_permutation_unsound`.
+        ///
+        /// # Warning
+        ///
+        /// __This is unsound: the copy constraints of the circuit are no
+        /// longer enforced, and a verifier accepting such proofs accepts
+        /// wires holding arbitrary values.__ Never use it in production. The
+        /// flag is not serialized.
+        #[cfg(feature = "debug-unsound")]
+        pub fn disable_permutation_unsound(&mut self) {
+            self.permutation_disabled = true;
+        }
+
+        pub(crate) fn v_h_coset_4n(&self) -> &Evaluations {
+            &self.v_h_coset_4n
+        }
+    }
+
+    /// Lazy, read-only view over the bytes of a serialized [`ProverKey`].
+    ///
+    /// Only the header and the offsets of each polynomial block are parsed
+    /// when the view is created, so single polynomials can be decoded on
+    /// demand without paying for a full [`ProverKey::from_slice`].
+    #[derive(Debug, Clone, Copy)]
+    pub struct ProverKeyView<'a> {
+        n: usize,
+        bytes: &'a [u8],
+        /// Offset and length in bytes of every serialized [`Polynomial`],
+        /// following the order used by [`ProverKey::to_var_bytes`].
+        polys: [(usize, usize); ProverKey::num_polys()],
+    }
+
+    impl<'a> ProverKeyView<'a> {
+        /// Names of the serialized polynomials, in serialization order.
+        const POLY_NAMES: [&'static str; ProverKey::num_polys()] =
+            ordered_blocks!(poly_names!());
+        const LEFT_SIGMA: usize =
+            Self::checked_index(Self::poly_index("left_sigma"));
+        const RIGHT_SIGMA: usize =
+            Self::checked_index(Self::poly_index("right_sigma"));
+        const OUT_SIGMA: usize =
+            Self::checked_index(Self::poly_index("out_sigma"));
+        const FOURTH_SIGMA: usize =
+            Self::checked_index(Self::poly_index("fourth_sigma"));
+
+        /// Returns `index` if it is the position of a serialized polynomial.
+        ///
+        /// Otherwise the indexing below is out of bounds, which fails the
+        /// evaluation of the constant calling it, so renaming a block breaks
+        /// the build instead of [`ProverKeyView::poly`] at runtime.
+        const fn checked_index(index: usize) -> usize {
+            [index][(index >= ProverKey::num_polys()) as usize]
+        }
+
+        /// Returns the position of the polynomial `name` in the serialized
+        /// key, or the number of polynomials if there is no such block.
+        const fn poly_index(name: &str) -> usize {
+            let name = name.as_bytes();
+            let mut index = 0;
+            while index < Self::POLY_NAMES.len() {
+                let candidate = Self::POLY_NAMES[index].as_bytes();
+                if candidate.len() == name.len() {
+                    let mut i = 0;
+                    while i < name.len() && candidate[i] == name[i] {
+                        i += 1;
+                    }
+                    if i == name.len() {
+                        return index;
+                    }
+                }
+                index += 1;
+            }
+            index
+        }
+
+        /// Validates the header of a serialized [`ProverKey`] and records
+        /// where each of its polynomials is stored.
+        pub fn new(bytes: &'a [u8]) -> Result<Self, Error> {
+            let mut buffer = bytes;
+            let n = circuit_size_from_u64(u64::from_reader(&mut buffer)?)?;
+            let evaluations_size =
+                ProverKey::evaluations_size_from_reader(&mut buffer)?;
+
+            let mut offset = 2 * u64::SIZE;
+            let mut polys = [(0, 0); ProverKey::num_polys()];
+            for poly in polys.iter_mut() {
+                let poly_len = (u64::from_reader(&mut buffer)? as usize)
+                    .saturating_mul(BlsScalar::SIZE);
+                offset += u64::SIZE;
+                *poly = (offset, poly_len);
+
+                let block = poly_len.saturating_add(evaluations_size);
+                if buffer.len() < block {
+                    return Err(Error::TruncatedProverKey {
+                        expected: block,
+                        available: buffer.len(),
+                    });
+                }
+                offset += block;
+                buffer = &bytes[offset..];
+            }
+
+            // The permutation linear evaluations and the vanishing
+            // polynomial evaluations close the serialized key.
+            let expected = evaluations_size.saturating_mul(2);
+            if buffer.len() < expected {
+                return Err(Error::TruncatedProverKey {
+                    expected,
+                    available: buffer.len(),
+                });
+            }
+
+            Ok(Self { n, bytes, polys })
+        }
+
+        /// Returns the circuit size of the viewed [`ProverKey`].
+        pub const fn n(&self) -> usize {
+            self.n
+        }
+
+        /// Decodes the left sigma [`Polynomial`] of the permutation.
+        pub fn left_sigma_poly(&self) -> Result<Polynomial, Error> {
+            self.poly(Self::LEFT_SIGMA)
+        }
+
+        /// Decodes the right sigma [`Polynomial`] of the permutation.
+        pub fn right_sigma_poly(&self) -> Result<Polynomial, Error> {
+            self.poly(Self::RIGHT_SIGMA)
+        }
+
+        /// Decodes the out sigma [`Polynomial`] of the permutation.
+        pub fn out_sigma_poly(&self) -> Result<Polynomial, Error> {
+            self.poly(Self::OUT_SIGMA)
+        }
+
+        /// Decodes the fourth sigma [`Polynomial`] of the permutation.
+        pub fn fourth_sigma_poly(&self) -> Result<Polynomial, Error> {
+            self.poly(Self::FOURTH_SIGMA)
+        }
+
+        fn poly(&self, index: usize) -> Result<Polynomial, Error> {
+            let (offset, len) = self.polys[index];
+            // Bounds were checked when the view was created.
+            Polynomial::from_slice(&self.bytes[offset..offset + len])
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod test {
+    use super::alloc::{GateHistogram, ProverKey, ProverKeyView};
+    use super::*;
+    use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
+    use ::alloc::vec::Vec;
+    use dusk_bls12_381::BlsScalar;
+    use rand_core::OsRng;
 
     fn rand_poly_eval(n: usize) -> (Polynomial, Evaluations) {
-        let polynomial = Polynomial::rand(n, &mut OsRng);
+        let polynomial = Polynomial::rand(n - 1, &mut OsRng);
         (polynomial, rand_evaluations(n))
     }
 
@@ -538,10 +2223,7 @@ mod test {
         evaluations
     }
 
-    #[test]
-    fn test_serialise_deserialise_prover_key() {
-        let n = 1 << 11;
-
+    fn rand_prover_key(n: usize) -> ProverKey {
         let q_m = rand_poly_eval(n);
         let q_l = rand_poly_eval(n);
         let q_r = rand_poly_eval(n);
@@ -602,7 +2284,7 @@ mod test {
             q_variable_group_add,
         };
 
-        let prover_key = ProverKey {
+        ProverKey {
             n,
             arithmetic,
             logic,
@@ -611,7 +2293,29 @@ mod test {
             variable_base,
             permutation,
             v_h_coset_4n,
-        };
+            #[cfg(feature = "debug-unsound")]
+            permutation_disabled: false,
+        }
+    }
+
+    /// Returns the multiples `1` up to `15` of the generator, in order.
+    fn sample_commitments() -> [Commitment; 15] {
+        let mut commitments = [Commitment::default(); 15];
+        commitments.iter_mut().zip(1u64..).for_each(|(c, i)| {
+            *c = Commitment::from(G1Affine::generator() * BlsScalar::from(i))
+        });
+        commitments
+    }
+
+    /// Returns a [`VerifierKey`] of circuit size `n` over
+    /// [`sample_commitments`].
+    fn sample_verifier_key(n: usize) -> VerifierKey {
+        VerifierKey::from_commitments(n, sample_commitments()).unwrap()
+    }
+
+    #[test]
+    fn test_serialise_deserialise_prover_key() {
+        let prover_key = rand_prover_key(1 << 11);
 
         let prover_key_bytes = prover_key.to_var_bytes();
         let pk = ProverKey::from_slice(&prover_key_bytes).unwrap();
@@ -620,6 +2324,704 @@ mod test {
         assert_eq!(pk.to_var_bytes(), prover_key.to_var_bytes());
     }
 
+    #[test]
+    fn test_prover_key_serialized_len() {
+        let n = 1 << 9;
+        let prover_key = rand_prover_key(n);
+
+        let (blocks, trailing) = prover_key.ordered_fields();
+        assert_eq!(blocks.len(), ProverKey::num_polys());
+        assert_eq!(blocks.len() + trailing.len(), ProverKey::num_evals());
+
+        let evaluations_size = 4 * n * BlsScalar::SIZE + EvaluationDomain::SIZE;
+        let expected = 2 * u64::SIZE
+            + blocks
+                .iter()
+                .map(|(_, (poly, _))| {
+                    u64::SIZE + poly.len() * BlsScalar::SIZE + evaluations_size
+                })
+                .sum::<usize>()
+            + trailing.len() * evaluations_size;
+        assert_eq!(prover_key.to_var_bytes().len(), expected);
+    }
+
+    #[test]
+    fn test_prover_key_content_hash() {
+        let prover_key = rand_prover_key(1 << 9);
+        let bytes = prover_key.to_var_bytes();
+
+        let expected = blake2b_simd::Params::new()
+            .hash_length(32)
+            .to_state()
+            .update(&bytes)
+            .finalize();
+        assert_eq!(&prover_key.content_hash()[..], expected.as_bytes());
+
+        let mut pk = prover_key.clone();
+        pk.arithmetic.q_m.1.evals[0] += BlsScalar::one();
+        assert_ne!(pk.content_hash(), prover_key.content_hash());
+    }
+
+    #[test]
+    fn test_prover_key_compact_bytes() {
+        use crate::test_fixtures::preprocessed_dummy_key;
+
+        // Arithmetic only circuit, leaving the logic, range and curve
+        // addition selectors empty
+        let (_, prover_key) = preprocessed_dummy_key(100);
+
+        let bytes = prover_key.to_var_bytes();
+        let compact = prover_key.to_var_bytes_compact();
+        let evaluations_size =
+            4 * prover_key.n * BlsScalar::SIZE + EvaluationDomain::SIZE;
+        assert_eq!(compact.len(), bytes.len() - 4 * evaluations_size);
+
+        let pk = ProverKey::from_slice_compact(&compact).unwrap();
+        assert_eq!(pk, prover_key);
+        assert_eq!(pk.to_var_bytes(), bytes);
+
+        // Keys without empty polynomials are encoded the same way
+        let prover_key = rand_prover_key(1 << 9);
+        assert_eq!(
+            prover_key.to_var_bytes_compact(),
+            prover_key.to_var_bytes()
+        );
+    }
+
+    #[test]
+    fn test_prover_key_trim_trailing_zeros() {
+        let prover_key = rand_prover_key(1 << 9);
+
+        let mut pk = prover_key.clone();
+        pk.arithmetic.q_m.0.coeffs.push(BlsScalar::zero());
+        pk.arithmetic
+            .q_c
+            .0
+            .coeffs
+            .extend_from_slice(&[BlsScalar::zero(); 2]);
+        pk.logic.q_c = pk.arithmetic.q_c.clone();
+        pk.fixed_base.q_c = pk.arithmetic.q_c.clone();
+        pk.permutation.out_sigma.0.coeffs.push(BlsScalar::zero());
+
+        // Polynomials of differing lengths round trip
+        let bytes = pk.to_var_bytes();
+        assert_eq!(
+            bytes.len(),
+            prover_key.to_var_bytes().len() + 4 * BlsScalar::SIZE
+        );
+        assert_eq!(ProverKey::from_slice(&bytes).unwrap(), pk);
+
+        pk.trim_trailing_zeros();
+        assert_eq!(pk, prover_key);
+    }
+
+    #[test]
+    fn test_deserialise_truncated_prover_key() {
+        let n = 1 << 9;
+        let prover_key_bytes = rand_prover_key(n).to_var_bytes();
+        let evaluations_size = 4 * n * BlsScalar::SIZE + EvaluationDomain::SIZE;
+
+        // Last Evaluations block cut short
+        let truncated = &prover_key_bytes[..prover_key_bytes.len() - 1];
+        assert!(matches!(
+            ProverKey::from_slice(truncated),
+            Err(Error::TruncatedProverKey { expected, available })
+                if expected == evaluations_size
+                    && available == evaluations_size - 1
+        ));
+
+        // Header announcing more blocks than the buffer can hold
+        let mut header = prover_key_bytes[..2 * u64::SIZE].to_vec();
+        header.extend_from_slice(&[0u8; 64]);
+        assert!(matches!(
+            ProverKey::from_slice(&header),
+            Err(Error::TruncatedProverKey { expected, available: 64 })
+                if expected == 17 * evaluations_size + 15 * u64::SIZE
+        ));
+
+        // Evaluations size not made of a domain and whole scalars
+        let mut misaligned = prover_key_bytes.clone();
+        misaligned[u64::SIZE..2 * u64::SIZE]
+            .copy_from_slice(&(evaluations_size as u64 + 1).to_bytes());
+        assert!(matches!(
+            ProverKey::from_slice(&misaligned),
+            Err(Error::BytesError(dusk_bytes::Error::InvalidData))
+        ));
+    }
+
+    #[test]
+    fn test_prover_key_diff() {
+        use super::alloc::ProverKeyField;
+
+        let prover_key = rand_prover_key(1 << 9);
+        assert!(prover_key.diff(&prover_key.clone()).is_empty());
+
+        let mut other = prover_key.clone();
+        other.n += 1;
+        other.arithmetic.q_m.0 = Polynomial::rand(1 << 9, &mut OsRng);
+        other.permutation.fourth_sigma.1 = rand_evaluations(1 << 9);
+        other.v_h_coset_4n = rand_evaluations(1 << 9);
+
+        assert_eq!(
+            prover_key.diff(&other),
+            [
+                ProverKeyField::N,
+                ProverKeyField::QM,
+                ProverKeyField::FourthSigma,
+                ProverKeyField::VHCoset,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prover_key_polynomials() {
+        let prover_key = rand_prover_key(1 << 9);
+
+        assert!(core::ptr::eq(
+            prover_key.q_m_polynomial(),
+            &prover_key.arithmetic.q_m.0
+        ));
+        assert!(core::ptr::eq(
+            prover_key.q_fixed_group_add_polynomial(),
+            &prover_key.fixed_base.q_fixed_group_add.0
+        ));
+        assert!(core::ptr::eq(
+            prover_key.fourth_sigma_polynomial(),
+            &prover_key.permutation.fourth_sigma.0
+        ));
+        assert!(core::ptr::eq(
+            prover_key.linear_evaluations(),
+            &prover_key.permutation.linear_evaluations
+        ));
+    }
+
+    #[test]
+    fn test_prover_key_gate_histogram() {
+        use crate::constraint_system::StandardComposer;
+        use crate::test_fixtures::preprocessed_key;
+
+        let mut composer = StandardComposer::new();
+        let a = composer.add_input(BlsScalar::from(3));
+        let b = composer.add_input(BlsScalar::from(5));
+        composer.range_gate(a, 8);
+        composer.xor_gate(a, b, 8);
+        composer.big_add(
+            (BlsScalar::one(), a),
+            (BlsScalar::one(), b),
+            None,
+            BlsScalar::zero(),
+            None,
+        );
+
+        let (_, prover_key) = preprocessed_key(&mut composer);
+        let histogram = prover_key.gate_histogram().unwrap();
+
+        let active = |selectors: &[BlsScalar]| {
+            selectors
+                .iter()
+                .filter(|s| **s != BlsScalar::zero())
+                .count()
+        };
+        assert_eq!(
+            histogram,
+            GateHistogram {
+                arithmetic: active(&composer.q_arith),
+                logic: active(&composer.q_logic),
+                range: active(&composer.q_range),
+                fixed_group_add: 0,
+                variable_group_add: 0,
+            }
+        );
+        assert!(histogram.logic > 0);
+        assert!(histogram.range > 0);
+    }
+
+    #[test]
+    fn test_prover_key_to_constraint_system() {
+        use super::alloc::ConstraintRow;
+        use crate::constraint_system::{
+            helper::dummy_gadget, StandardComposer,
+        };
+        use crate::test_fixtures::preprocessed_key;
+
+        let mut composer = StandardComposer::new();
+        dummy_gadget(20, &mut composer);
+        let a = composer.add_input(BlsScalar::from(5));
+        let b = composer.add_input(BlsScalar::from(6));
+        composer.xor_gate(a, b, 8);
+        composer.range_gate(a, 8);
+
+        let (_, prover_key) = preprocessed_key(&mut composer);
+        let constraints = prover_key.to_constraint_system().unwrap();
+        assert_eq!(constraints.rows.len(), prover_key.n);
+
+        // Rows padding the circuit have no selector set
+        let selector = |q: &[BlsScalar], i: usize| {
+            q.get(i).copied().unwrap_or_else(BlsScalar::zero)
+        };
+        for (i, row) in constraints.rows.iter().enumerate() {
+            let expected = ConstraintRow {
+                q_m: selector(&composer.q_m, i),
+                q_l: selector(&composer.q_l, i),
+                q_r: selector(&composer.q_r, i),
+                q_o: selector(&composer.q_o, i),
+                q_4: selector(&composer.q_4, i),
+                q_c: selector(&composer.q_c, i),
+                q_arith: selector(&composer.q_arith, i),
+                q_logic: selector(&composer.q_logic, i),
+                q_range: selector(&composer.q_range, i),
+                q_fixed_group_add: selector(&composer.q_fixed_group_add, i),
+                q_variable_group_add: selector(
+                    &composer.q_variable_group_add,
+                    i,
+                ),
+            };
+            assert_eq!(row, &expected);
+        }
+        assert!(constraints
+            .rows
+            .iter()
+            .any(|row| row.q_logic != BlsScalar::zero()));
+    }
+
+    #[test]
+    fn test_prover_key_memory_estimate() {
+        let prover_key = rand_prover_key(1 << 9);
+        let estimate = prover_key.memory_estimate();
+
+        // The key estimate only leaves out headers and evaluation domains
+        let serialized = prover_key.to_var_bytes().len();
+        assert!(estimate.key_bytes < serialized);
+        assert!(serialized - estimate.key_bytes < serialized / 100);
+
+        assert_eq!(prover_key.estimated_proving_memory(), estimate.total());
+        assert_eq!(
+            estimate.total(),
+            estimate.key_bytes
+                + estimate.witness_bytes
+                + estimate.coset_bytes
+                + estimate.quotient_bytes
+        );
+    }
+
+    #[test]
+    fn test_prover_key_chunks() {
+        let prover_key = rand_prover_key(1 << 9);
+        let len = prover_key.to_var_bytes().len();
+
+        // Smaller than a polynomial, uneven, and larger than the whole key
+        for &max_chunk in &[1000, 4099, len, 2 * len] {
+            let chunks = prover_key.to_var_bytes_chunked(max_chunk);
+            assert!(chunks.iter().all(|chunk| chunk.len() <= max_chunk));
+            assert_eq!(chunks.len(), (len + max_chunk - 1) / max_chunk);
+
+            let got = ProverKey::from_chunks(&chunks).unwrap();
+            assert_eq!(got, prover_key);
+        }
+    }
+
+    #[test]
+    fn test_prover_key_report() {
+        let mut prover_key = rand_prover_key(1 << 9);
+        prover_key.logic.q_logic.0 = Polynomial::zero();
+
+        let report = prover_key.report();
+        assert_eq!(report.len(), 15);
+        assert_eq!(
+            report[0],
+            (
+                "q_m",
+                prover_key.q_m_polynomial().degree(),
+                prover_key.q_m_polynomial().len()
+            )
+        );
+        assert_eq!(report[7], ("q_logic", 0, 0));
+        assert_eq!(report[14].0, "fourth_sigma");
+    }
+
+    #[test]
+    fn test_prover_key_shared_selectors() {
+        let prover_key = rand_prover_key(1 << 9);
+        prover_key.validate_shared_selectors().unwrap();
+
+        let mut pk = prover_key.clone();
+        pk.logic.q_c.1.evals[0] += BlsScalar::one();
+        assert!(matches!(
+            pk.validate_shared_selectors(),
+            Err(Error::InconsistentSharedSelector { selector: "q_c" })
+        ));
+
+        let mut pk = prover_key;
+        pk.fixed_base.q_r.0.coeffs[3] += BlsScalar::one();
+        assert!(matches!(
+            pk.validate_shared_selectors(),
+            Err(Error::InconsistentSharedSelector { selector: "q_r" })
+        ));
+    }
+
+    #[test]
+    fn test_prover_key_permutation_map() {
+        use crate::permutation::constants::{K1, K2, K3};
+
+        let n = 4;
+        let domain = EvaluationDomain::new(n).unwrap();
+        let roots: Vec<_> = domain.elements().collect();
+        let ks = [BlsScalar::one(), K1, K2, K3];
+        let position = |index: usize| ks[index / n] * roots[index % n];
+
+        // Swap row 0 of the left wires with row 1 of the right ones, and
+        // cycle row 2 of the output wires to row 3 of the fourth and left
+        // ones
+        let mut map: Vec<usize> = (0..4 * n).collect();
+        map.swap(0, n + 1);
+        map[2 * n + 2] = 3 * n + 3;
+        map[3 * n + 3] = 3;
+        map[3] = 2 * n + 2;
+
+        let mut prover_key = rand_prover_key(n);
+        let sigmas = [
+            &mut prover_key.permutation.left_sigma.0,
+            &mut prover_key.permutation.right_sigma.0,
+            &mut prover_key.permutation.out_sigma.0,
+            &mut prover_key.permutation.fourth_sigma.0,
+        ];
+        for (wire, sigma) in core::array::IntoIter::new(sigmas).enumerate() {
+            let values: Vec<_> =
+                (0..n).map(|row| position(map[wire * n + row])).collect();
+            *sigma = Polynomial::from_coefficients_vec(domain.ifft(&values));
+        }
+
+        assert_eq!(prover_key.permutation_map().unwrap(), map);
+        prover_key.validate_permutation().unwrap();
+
+        prover_key.permutation.out_sigma.0.coeffs[0] += BlsScalar::one();
+        assert!(matches!(
+            prover_key.permutation_map(),
+            Err(Error::InvalidPermutation)
+        ));
+    }
+
+    #[test]
+    fn test_prover_key_validate_permutation() {
+        use crate::test_fixtures::preprocessed_dummy_key;
+
+        let (_, prover_key) = preprocessed_dummy_key(20);
+        prover_key.validate_permutation().unwrap();
+
+        // Two wires sent to the same positions
+        let mut pk = prover_key.clone();
+        pk.permutation.right_sigma = pk.permutation.left_sigma.clone();
+        assert!(matches!(
+            pk.validate_permutation(),
+            Err(Error::InvalidPermutation)
+        ));
+
+        // A single position moved out of the cosets
+        let mut pk = prover_key;
+        pk.permutation.out_sigma.0.coeffs[0] += BlsScalar::one();
+        assert!(matches!(
+            pk.validate_permutation(),
+            Err(Error::InvalidPermutation)
+        ));
+    }
+
+    #[test]
+    fn test_prover_key_truncate_to() {
+        use crate::constraint_system::{
+            helper::dummy_gadget, StandardComposer,
+        };
+        use crate::test_fixtures::preprocessed_key;
+
+        let preprocess = |extra_rows: usize| {
+            let mut composer = StandardComposer::new();
+            dummy_gadget(20, &mut composer);
+
+            // Rows without gates nor copy constraints, like the padding
+            let zero_var = composer.zero_var();
+            for _ in 0..extra_rows {
+                for q in [
+                    &mut composer.q_m,
+                    &mut composer.q_l,
+                    &mut composer.q_r,
+                    &mut composer.q_o,
+                    &mut composer.q_c,
+                    &mut composer.q_4,
+                    &mut composer.q_arith,
+                    &mut composer.q_range,
+                    &mut composer.q_logic,
+                    &mut composer.q_fixed_group_add,
+                    &mut composer.q_variable_group_add,
+                ] {
+                    q.push(BlsScalar::zero());
+                }
+                for w in [
+                    &mut composer.w_l,
+                    &mut composer.w_r,
+                    &mut composer.w_o,
+                    &mut composer.w_4,
+                ] {
+                    w.push(zero_var);
+                }
+                composer.n += 1;
+            }
+            preprocessed_key(&mut composer)
+        };
+
+        let (commit_key, small) = preprocess(0);
+        let (_, large) = preprocess(small.n);
+        assert_eq!(large.n, 2 * small.n);
+
+        let truncated = large.truncate_to(small.n).unwrap();
+        assert_eq!(truncated, small);
+        assert_eq!(
+            truncated.to_verifier_key(&commit_key).unwrap(),
+            small.to_verifier_key(&commit_key).unwrap()
+        );
+        assert_eq!(large.truncate_to(large.n).unwrap(), large);
+
+        assert!(matches!(
+            large.truncate_to(2 * large.n),
+            Err(Error::InvalidTruncationSize { .. })
+        ));
+        assert!(matches!(
+            large.truncate_to(small.n - 1),
+            Err(Error::InvalidTruncationSize { .. })
+        ));
+        assert!(matches!(
+            large.truncate_to(small.n / 2),
+            Err(Error::TruncatedSupport { .. })
+        ));
+    }
+
+    #[test]
+    fn test_prover_key_degrees() {
+        let n = 1 << 9;
+        let prover_key = rand_prover_key(n);
+        prover_key.validate_degrees().unwrap();
+
+        let mut pk = prover_key.clone();
+        pk.permutation.out_sigma.0 = Polynomial::rand(n, &mut OsRng);
+        assert!(matches!(
+            pk.validate_degrees(),
+            Err(Error::SelectorDegreeTooHigh { name: "out_sigma", degree, n: 512 })
+                if degree == n
+        ));
+        assert!(matches!(
+            ProverKey::from_slice(&pk.to_var_bytes()),
+            Err(Error::SelectorDegreeTooHigh {
+                name: "out_sigma",
+                ..
+            })
+        ));
+
+        let mut pk = prover_key;
+        pk.v_h_coset_4n = rand_evaluations(n / 2);
+        assert!(matches!(
+            pk.validate_degrees(),
+            Err(Error::MismatchedEvalDomainSize {
+                domain: 2048,
+                evaluations: 1024
+            })
+        ));
+    }
+
+    #[test]
+    fn test_prover_key_with_domain() {
+        let prover_key = rand_prover_key(1 << 9);
+        let bytes = prover_key.to_var_bytes();
+
+        let domain = EvaluationDomain::new(4 * (1 << 9)).unwrap();
+        let pk = ProverKey::from_slice_with_domain(&bytes, domain).unwrap();
+        assert_eq!(pk, prover_key);
+
+        // The domain replaces the stored one in every Evaluations
+        let mut other = domain;
+        other.group_gen = BlsScalar::one();
+        let pk = ProverKey::from_slice_with_domain(&bytes, other).unwrap();
+        let domain_bytes = other.to_bytes();
+        for evals in [&pk.v_h_coset_4n, &pk.permutation.left_sigma.1] {
+            assert!(evals.to_var_bytes().starts_with(&domain_bytes));
+        }
+        assert_eq!(
+            pk.permutation.left_sigma.1.evals,
+            prover_key.permutation.left_sigma.1.evals
+        );
+
+        let small = EvaluationDomain::new(1 << 9).unwrap();
+        assert!(matches!(
+            ProverKey::from_slice_with_domain(&bytes, small),
+            Err(Error::MismatchedEvalDomainSize {
+                domain: 512,
+                evaluations: 2048,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_prover_key_permutation_bytes() {
+        let prover_key = rand_prover_key(1 << 9);
+        let other = rand_prover_key(1 << 9);
+
+        let mut patched = other.clone();
+        patched
+            .set_permutation_from_bytes(&prover_key.permutation_to_bytes())
+            .unwrap();
+        assert_eq!(patched.permutation, prover_key.permutation);
+        assert_eq!(patched.arithmetic, other.arithmetic);
+        assert_eq!(patched.v_h_coset_4n, other.v_h_coset_4n);
+        assert_eq!(
+            patched.permutation_to_bytes(),
+            prover_key.permutation_to_bytes()
+        );
+
+        // Keys of another size are left untouched
+        let mut smaller = rand_prover_key(1 << 8);
+        let res = smaller
+            .clone()
+            .set_permutation_from_bytes(&prover_key.permutation_to_bytes());
+        assert!(matches!(
+            res,
+            Err(Error::MismatchedCircuitSize {
+                expected: 256,
+                found: 512
+            })
+        ));
+
+        let bytes = smaller.permutation_to_bytes();
+        let res = smaller.set_permutation_from_bytes(&bytes[..bytes.len() - 1]);
+        assert!(matches!(res, Err(Error::TruncatedProverKey { .. })));
+        assert_eq!(smaller.permutation_to_bytes(), bytes);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_prover_key_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut prover_key = rand_prover_key(1 << 9);
+        prover_key.zeroize();
+
+        assert!(prover_key.q_m_polynomial().is_empty());
+        assert!(prover_key.logic.q_c.0.is_empty());
+        assert!(prover_key.fixed_base.q_c.1.evals.is_empty());
+        assert!(prover_key.permutation.linear_evaluations.evals.is_empty());
+        assert!(prover_key.v_h_coset_4n.evals.is_empty());
+    }
+
+    #[test]
+    fn test_prover_key_to_verifier_key() {
+        use crate::constraint_system::{
+            helper::dummy_gadget, StandardComposer,
+        };
+        use crate::test_fixtures::preprocessed_key;
+        use merlin::Transcript;
+
+        let mut composer = StandardComposer::new();
+        dummy_gadget(100, &mut composer);
+        let (commit_key, prover_key) = preprocessed_key(&mut composer);
+        let verifier_key = composer
+            .preprocess_verifier(&commit_key, &mut Transcript::new(b"test"))
+            .unwrap();
+
+        let derived = prover_key.to_verifier_key(&commit_key).unwrap();
+        assert_eq!(derived.to_bytes(), verifier_key.to_bytes());
+        assert_eq!(
+            prover_key.to_verifier_key(&commit_key).unwrap().to_bytes(),
+            derived.to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_prover_key_to_verifier_key_constant_selector() {
+        use crate::commitment_scheme::kzg10::{Commitment, CommitmentCache};
+        use crate::test_fixtures::preprocessed_dummy_key;
+
+        let (commit_key, mut prover_key) = preprocessed_dummy_key(100);
+
+        // A selector enabled with the same value on every row
+        prover_key.range.q_range.0 =
+            Polynomial::from_coefficients_vec(vec![BlsScalar::from(5)]);
+        let expected =
+            Commitment::from(commit_key.powers_of_g[0] * BlsScalar::from(5));
+
+        let derived = prover_key.to_verifier_key(&commit_key).unwrap();
+        assert_eq!(derived.range.q_range, expected);
+        let cached = prover_key
+            .to_verifier_key_cached(&commit_key, &mut CommitmentCache::new())
+            .unwrap();
+        assert_eq!(cached.to_bytes(), derived.to_bytes());
+
+        // An empty selector commits to the identity
+        prover_key.range.q_range.0 = Polynomial { coeffs: vec![] };
+        let derived = prover_key.to_verifier_key(&commit_key).unwrap();
+        assert!(derived.range.q_range.is_identity());
+    }
+
+    #[test]
+    fn test_prover_key_to_verifier_key_cached() {
+        use crate::commitment_scheme::kzg10::CommitmentCache;
+        use crate::test_fixtures::preprocessed_dummy_key;
+
+        let (commit_key, prover_key) = preprocessed_dummy_key(100);
+        let expected = prover_key.to_verifier_key(&commit_key).unwrap();
+
+        let mut cache = CommitmentCache::new();
+        let cached = prover_key
+            .to_verifier_key_cached(&commit_key, &mut cache)
+            .unwrap();
+        assert_eq!(cached.to_bytes(), expected.to_bytes());
+        let entries = cache.len();
+        assert!(entries > 0);
+
+        // Every commitment is now a cache hit
+        let cached = prover_key
+            .to_verifier_key_cached(&commit_key, &mut cache)
+            .unwrap();
+        assert_eq!(cached.to_bytes(), expected.to_bytes());
+        assert_eq!(cache.len(), entries);
+    }
+
+    #[test]
+    fn test_prover_key_view() {
+        let prover_key = rand_prover_key(1 << 9);
+        let prover_key_bytes = prover_key.to_var_bytes();
+
+        let view = ProverKeyView::new(&prover_key_bytes).unwrap();
+        assert_eq!(view.n(), prover_key.n);
+        assert_eq!(
+            view.left_sigma_poly().unwrap(),
+            prover_key.permutation.left_sigma.0
+        );
+        assert_eq!(
+            view.right_sigma_poly().unwrap(),
+            prover_key.permutation.right_sigma.0
+        );
+        assert_eq!(
+            view.out_sigma_poly().unwrap(),
+            prover_key.permutation.out_sigma.0
+        );
+        assert_eq!(
+            view.fourth_sigma_poly().unwrap(),
+            prover_key.permutation.fourth_sigma.0
+        );
+
+        let truncated = &prover_key_bytes[..prover_key_bytes.len() - 1];
+        assert!(matches!(
+            ProverKeyView::new(truncated),
+            Err(Error::TruncatedProverKey { .. })
+        ));
+
+        // The evaluations size must hold a domain and whole scalars
+        let mut misaligned = prover_key_bytes.clone();
+        let evaluations_size = (EvaluationDomain::SIZE + 1) as u64;
+        misaligned[u64::SIZE..2 * u64::SIZE]
+            .copy_from_slice(&evaluations_size.to_le_bytes());
+        assert!(matches!(
+            ProverKeyView::new(&misaligned),
+            Err(Error::BytesError(dusk_bytes::Error::InvalidData))
+        ));
+    }
+
     #[test]
     fn test_serialise_deserialise_verifier_key() {
         use crate::commitment_scheme::kzg10::Commitment;
@@ -685,6 +3087,8 @@ mod test {
             fixed_base,
             variable_base,
             permutation,
+            #[cfg(feature = "debug-unsound")]
+            permutation_disabled: false,
         };
 
         let verifier_key_bytes = verifier_key.to_bytes();
@@ -692,4 +3096,542 @@ mod test {
 
         assert_eq!(got, verifier_key);
     }
+
+    #[test]
+    fn test_verifier_key_bytes_unchecked() {
+        use crate::commitment_scheme::kzg10::Commitment;
+        use dusk_bls12_381::G1Affine;
+
+        let commitment = |i: u64| {
+            Commitment::from(G1Affine::generator() * BlsScalar::from(i))
+        };
+        let verifier_key = VerifierKey::from_polynomial_commitments(
+            1 << 5,
+            commitment(1),
+            commitment(2),
+            commitment(3),
+            commitment(4),
+            commitment(5),
+            commitment(6),
+            commitment(7),
+            commitment(8),
+            commitment(9),
+            commitment(10),
+            commitment(11),
+            commitment(12),
+            commitment(13),
+            commitment(14),
+            Commitment::default(),
+        );
+
+        let got = unsafe {
+            let bytes = verifier_key.to_raw_bytes();
+            VerifierKey::from_slice_unchecked(&bytes)
+        };
+
+        assert_eq!(got, verifier_key);
+
+        let bytes = verifier_key.to_raw_bytes();
+        assert_eq!(VerifierKey::from_raw_bytes(&bytes).unwrap(), verifier_key);
+
+        let mut corrupted = bytes;
+        corrupted[u64::SIZE + 48] ^= 1;
+        assert!(matches!(
+            VerifierKey::from_raw_bytes(&corrupted),
+            Err(Error::PointMalformed)
+        ));
+    }
+
+    #[test]
+    fn test_verifier_key_commitments() {
+        use crate::commitment_scheme::kzg10::Commitment;
+        use dusk_bls12_381::G1Affine;
+
+        let commitments: Vec<_> = (1..=15u64)
+            .map(|i| {
+                Commitment::from(G1Affine::generator() * BlsScalar::from(i))
+            })
+            .collect();
+        let c = &commitments;
+        let verifier_key = VerifierKey::from_polynomial_commitments(
+            1 << 5,
+            c[0],
+            c[1],
+            c[2],
+            c[3],
+            c[4],
+            c[5],
+            c[6],
+            c[7],
+            c[8],
+            c[9],
+            c[10],
+            c[11],
+            c[12],
+            c[13],
+            c[14],
+        );
+
+        let labels: Vec<_> =
+            verifier_key.commitments().map(|(label, _)| label).collect();
+        assert_eq!(
+            labels,
+            [
+                "q_m",
+                "q_l",
+                "q_r",
+                "q_o",
+                "q_c",
+                "q_4",
+                "q_arith",
+                "q_range",
+                "q_logic",
+                "q_variable_group_add",
+                "q_fixed_group_add",
+                "left_sigma",
+                "right_sigma",
+                "out_sigma",
+                "fourth_sigma",
+            ]
+        );
+
+        // Every commitment is yielded exactly once
+        let mut yielded: Vec<_> = verifier_key
+            .commitments()
+            .map(|(_, c)| c.to_bytes())
+            .collect();
+        let mut expected: Vec<_> =
+            commitments.iter().map(|c| c.to_bytes()).collect();
+        yielded.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(yielded, expected);
+    }
+
+    #[test]
+    fn test_verifier_key_commitment_orders() {
+        let verifier_key = sample_verifier_key(1 << 10);
+
+        let transcript: Vec<_> =
+            verifier_key.commitments().map(|(label, _)| label).collect();
+        assert_eq!(
+            transcript,
+            [
+                "q_m",
+                "q_l",
+                "q_r",
+                "q_o",
+                "q_c",
+                "q_4",
+                "q_arith",
+                "q_range",
+                "q_logic",
+                "q_variable_group_add",
+                "q_fixed_group_add",
+                "left_sigma",
+                "right_sigma",
+                "out_sigma",
+                "fourth_sigma",
+            ]
+        );
+
+        // Serialization follows the order of the commitments given to
+        // `from_commitments`
+        let serialized: Vec<_> = verifier_key
+            .serialized_commitments()
+            .map(|(_, commitment)| *commitment)
+            .collect();
+        assert_eq!(serialized, commitments);
+        assert_eq!(
+            verifier_key
+                .serialized_commitments()
+                .map(|(label, _)| label)
+                .collect::<Vec<_>>(),
+            [
+                "q_m",
+                "q_l",
+                "q_r",
+                "q_o",
+                "q_4",
+                "q_c",
+                "q_arith",
+                "q_logic",
+                "q_range",
+                "q_fixed_group_add",
+                "q_variable_group_add",
+                "left_sigma",
+                "right_sigma",
+                "out_sigma",
+                "fourth_sigma",
+            ]
+        );
+
+        // Both orders hold the same labeled commitments
+        let mut sorted: Vec<_> = verifier_key.commitments().collect();
+        let mut sorted_serialized: Vec<_> =
+            verifier_key.serialized_commitments().collect();
+        sorted.sort_by_key(|(label, _)| *label);
+        sorted_serialized.sort_by_key(|(label, _)| *label);
+        assert_eq!(sorted, sorted_serialized);
+    }
+
+    #[test]
+    fn test_verifier_key_seed_with_context() {
+        use merlin::Transcript;
+
+        let verifier_key = sample_verifier_key(1 << 10);
+
+        let challenge = |ctx: Option<&[u8]>| {
+            let mut transcript = Transcript::new(b"test");
+            match ctx {
+                Some(ctx) => verifier_key
+                    .seed_transcript_with_context(&mut transcript, ctx),
+                None => verifier_key.seed_transcript(&mut transcript),
+            }
+            let mut challenge = [0u8; 32];
+            transcript.challenge_bytes(b"check", &mut challenge);
+            challenge
+        };
+
+        assert_eq!(challenge(Some(b"inner")), challenge(Some(b"inner")));
+        assert_ne!(challenge(Some(b"inner")), challenge(None));
+        assert_ne!(challenge(Some(b"inner")), challenge(Some(b"outer")));
+    }
+
+    #[test]
+    fn test_verifier_key_serialized_commitments_bytes() {
+        let verifier_key = sample_verifier_key(1 << 10);
+
+        let mut bytes = (verifier_key.n as u64).to_bytes().to_vec();
+        verifier_key
+            .serialized_commitments()
+            .for_each(|(_, c)| bytes.extend_from_slice(&c.to_bytes()));
+        assert_eq!(&bytes[..], &verifier_key.to_bytes()[..]);
+    }
+
+    #[test]
+    fn test_verifier_key_transcript_bytes() {
+        use merlin::Transcript;
+
+        let verifier_key = sample_verifier_key(1 << 10);
+        let bytes = verifier_key.transcript_bytes();
+
+        let mut seeded = Transcript::new(b"test");
+        verifier_key.seed_transcript(&mut seeded);
+
+        // Replay the framed messages, expecting the labels in transcript
+        // order
+        let mut rebuilt = Transcript::new(b"test");
+        let mut rest = &bytes[..];
+        let labels = verifier_key
+            .commitments()
+            .map(|(label, _)| label.as_bytes())
+            .chain([b"dom-sep" as &[u8], b"n"].iter().copied());
+        for label in labels {
+            assert_eq!(&rest[..label.len()], label);
+            rest = &rest[label.len()..];
+            let mut len = [0u8; 4];
+            len.copy_from_slice(&rest[..4]);
+            let (message, tail) =
+                rest[4..].split_at(u32::from_le_bytes(len) as usize);
+            rebuilt.append_message(label, message);
+            rest = tail;
+        }
+        assert!(rest.is_empty());
+
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        seeded.challenge_bytes(b"check", &mut a);
+        rebuilt.challenge_bytes(b"check", &mut b);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_verifier_key_digest() {
+        use merlin::Transcript;
+
+        let verifier_key = sample_verifier_key(1 << 10);
+
+        let digest = verifier_key.digest();
+        let expected = blake2b_simd::Params::new()
+            .hash_length(32)
+            .to_state()
+            .update(&verifier_key.transcript_bytes())
+            .finalize();
+        assert_eq!(&digest[..], expected.as_bytes());
+
+        // Another circuit size changes the digest
+        let other = VerifierKey::from_commitments(1 << 9, commitments).unwrap();
+        assert_ne!(other.digest(), digest);
+
+        let challenge = |seed: &dyn Fn(&mut Transcript)| {
+            let mut transcript = Transcript::new(b"test");
+            seed(&mut transcript);
+            let mut challenge = [0u8; 32];
+            transcript.challenge_bytes(b"check", &mut challenge);
+            challenge
+        };
+        let digest_seeded = challenge(&|t| {
+            verifier_key.seed_transcript_digest(t, &digest).unwrap()
+        });
+        assert_eq!(
+            digest_seeded,
+            challenge(&|t| {
+                t.append_message(b"dom-sep", b"vk_digest");
+                t.append_message(b"vk_digest", &digest);
+            })
+        );
+        assert_ne!(
+            digest_seeded,
+            challenge(&|t| verifier_key.seed_transcript(t))
+        );
+
+        // The digest of another key is rejected
+        let mut transcript = Transcript::new(b"test");
+        assert!(matches!(
+            verifier_key
+                .seed_transcript_digest(&mut transcript, &other.digest()),
+            Err(Error::MismatchedVerifierKeyDigest)
+        ));
+    }
+
+    #[test]
+    fn test_verifier_key_compatible_with() {
+        use crate::commitment_scheme::kzg10::Commitment;
+
+        let commitments = [Commitment::from(G1Affine::generator()); 15];
+        let key = |n| VerifierKey::from_commitments(n, commitments).unwrap();
+
+        // Circuits padded to the same size are compatible
+        assert!(key(1000).compatible_with(&key(1 << 10)));
+        assert!(key(1 << 10).assert_compatible(&key(513)).is_ok());
+
+        assert!(!key(1 << 10).compatible_with(&key(1025)));
+        assert!(matches!(
+            key(1 << 10).assert_compatible(&key(1025)),
+            Err(Error::IncompatibleVerifierKeys {
+                size: 1024,
+                other_size: 2048
+            })
+        ));
+    }
+
+    #[test]
+    fn test_verifier_key_hash() {
+        use crate::commitment_scheme::kzg10::Commitment;
+        use hashbrown::HashMap;
+
+        let mut commitments = [Commitment::from(G1Affine::generator()); 15];
+        commitments[3] = Commitment::default();
+        let key = |n| VerifierKey::from_commitments(n, commitments).unwrap();
+
+        let mut results = HashMap::new();
+        results.insert(key(1 << 10), true);
+        results.insert(key(1000), false);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.get(&key(1 << 10)), Some(&true));
+        assert_eq!(results.get(&key(1000)), Some(&false));
+        assert_eq!(results.get(&key(1001)), None);
+
+        results.insert(key(1000), true);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.get(&key(1000)), Some(&true));
+    }
+
+    #[test]
+    fn test_verifier_key_from_commitments_validated() {
+        use crate::commitment_scheme::kzg10::Commitment;
+
+        let mut commitments = sample_commitments();
+        assert_eq!(
+            VerifierKey::from_commitments_validated(1 << 10, commitments)
+                .unwrap(),
+            VerifierKey::from_commitments(1 << 10, commitments).unwrap()
+        );
+
+        // Tamper with the y coordinate of the generator
+        let mut raw = G1Affine::generator().to_raw_bytes();
+        raw[48] ^= 1;
+        let point = unsafe { G1Affine::from_slice_unchecked(&raw) };
+        commitments[7] = Commitment(point);
+        assert!(matches!(
+            VerifierKey::from_commitments_validated(1 << 10, commitments),
+            Err(Error::PointNotOnCurve { index: 7 })
+        ));
+    }
+
+    #[test]
+    fn test_verifier_key_from_raw_bytes_batch_validated() {
+        use crate::commitment_scheme::kzg10::Commitment;
+
+        let keys: Vec<_> = (0..12u64)
+            .map(|k| {
+                let mut commitments = sample_commitments();
+                commitments.iter_mut().for_each(|c| {
+                    *c = Commitment::from(c.0 * BlsScalar::from(k + 1))
+                });
+                VerifierKey::from_commitments(1 << (k + 1), commitments)
+                    .unwrap()
+            })
+            .collect();
+        let mut bytes: Vec<_> = keys.iter().map(|k| k.to_raw_bytes()).collect();
+
+        for count in [0, 1, keys.len()] {
+            assert_eq!(
+                VerifierKey::from_raw_bytes_batch_validated(&bytes[..count])
+                    .unwrap(),
+                keys[..count]
+            );
+        }
+
+        // Tamper with the y coordinate of the third commitment of the fourth
+        // key
+        bytes[3][u64::SIZE + 2 * G1Affine::RAW_SIZE + 48] ^= 1;
+        assert!(matches!(
+            VerifierKey::from_raw_bytes_batch_validated(&bytes),
+            Err(Error::PointNotOnCurve { index: 47 })
+        ));
+        assert!(VerifierKey::from_raw_bytes(&bytes[3]).is_err());
+    }
+
+    #[test]
+    fn test_verifier_key_commitments_bytes() {
+        use crate::commitment_scheme::kzg10::Commitment;
+
+        let verifier_key = sample_verifier_key(1 << 10);
+
+        let bytes = verifier_key.commitments_to_bytes();
+        assert_eq!(&bytes[..], &verifier_key.to_bytes()[u64::SIZE..]);
+
+        let got = VerifierKey::from_commitments_bytes(1 << 10, &bytes).unwrap();
+        assert_eq!(got, verifier_key);
+
+        assert!(matches!(
+            VerifierKey::from_commitments_bytes(0, &bytes),
+            Err(Error::CircuitSizeIsZero)
+        ));
+        let mut invalid = bytes;
+        invalid[Commitment::SIZE - 1] ^= 1;
+        assert!(VerifierKey::from_commitments_bytes(1 << 10, &invalid).is_err());
+    }
+
+    #[test]
+    fn test_verifier_key_golden_bytes() {
+        // One hex encoded field per line, each preceded by its label. When
+        // the layout changes on purpose, regenerate the file from the key
+        // below.
+        let golden: Vec<u8> = include_str!("verifier_key.hex")
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .flat_map(|line| {
+                (0..line.len()).step_by(2).map(move |i| {
+                    u8::from_str_radix(&line[i..i + 2], 16).unwrap()
+                })
+            })
+            .collect();
+
+        let verifier_key = sample_verifier_key(1 << 10);
+
+        assert_eq!(&verifier_key.to_bytes()[..], &golden[..]);
+    }
+
+    #[test]
+    fn test_verifier_key_diff() {
+        use crate::commitment_scheme::kzg10::Commitment;
+        use dusk_bls12_381::G1Affine;
+
+        let identity = Commitment::default();
+        let generator = Commitment(G1Affine::generator());
+        let verifier_key = |n, q_range, out_sigma| {
+            VerifierKey::from_polynomial_commitments(
+                n, generator, generator, generator, generator, generator,
+                generator, generator, generator, q_range, generator, generator,
+                generator, generator, out_sigma, generator,
+            )
+        };
+
+        let a = verifier_key(1 << 5, generator, generator);
+        assert!(a.diff(&a).is_empty());
+
+        let b = verifier_key(1 << 6, identity, identity);
+        assert_eq!(
+            a.diff(&b),
+            [
+                VerifierKeyField::N,
+                VerifierKeyField::QRange,
+                VerifierKeyField::OutSigma
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verifier_key_from_commitments() {
+        use crate::commitment_scheme::kzg10::Commitment;
+
+        let verifier_key = sample_verifier_key(1 << 5);
+
+        // The commitments are serialized in the order they were given
+        let bytes = verifier_key.to_bytes();
+        assert_eq!(bytes[..u64::SIZE], (1u64 << 5).to_bytes());
+        bytes[u64::SIZE..]
+            .chunks_exact(Commitment::SIZE)
+            .zip(commitments.iter())
+            .for_each(|(bytes, c)| assert_eq!(bytes, c.to_bytes()));
+
+        assert!(matches!(
+            VerifierKey::from_commitments(0, commitments),
+            Err(Error::CircuitSizeIsZero)
+        ));
+    }
+
+    #[test]
+    fn test_validate_degenerate_verifier_key() {
+        use crate::commitment_scheme::kzg10::Commitment;
+        use dusk_bls12_381::G1Affine;
+
+        let identity = Commitment::default();
+        let generator = Commitment(G1Affine::generator());
+        let verifier_key = |q_arith| {
+            VerifierKey::from_polynomial_commitments(
+                1 << 5,
+                identity,
+                identity,
+                identity,
+                identity,
+                identity,
+                identity,
+                q_arith,
+                generator,
+                generator,
+                generator,
+                generator,
+                generator,
+                generator,
+                generator,
+                generator,
+            )
+        };
+
+        assert!(matches!(
+            verifier_key(identity).validate(),
+            Err(Error::DegenerateCircuit)
+        ));
+        assert!(verifier_key(generator).validate().is_ok());
+    }
+
+    #[test]
+    fn test_deserialise_oversized_circuit() {
+        let mut verifier_key_bytes = [0u8; VerifierKey::SIZE];
+        verifier_key_bytes[..u64::SIZE].copy_from_slice(&u64::MAX.to_bytes());
+        assert!(matches!(
+            VerifierKey::from_bytes(&verifier_key_bytes),
+            Err(Error::CircuitSizeOverflow { n: u64::MAX })
+        ));
+
+        let mut prover_key_bytes = vec![0u8; 2 * u64::SIZE];
+        prover_key_bytes[..u64::SIZE].copy_from_slice(&u64::MAX.to_bytes());
+        assert!(matches!(
+            ProverKey::from_slice(&prover_key_bytes),
+            Err(Error::CircuitSizeOverflow { n: u64::MAX })
+        ));
+    }
 }
@@ -4,9 +4,13 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use crate::error::Error;
 use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
 use crate::permutation::constants::{K1, K2, K3};
+use alloc::vec::Vec;
 use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+use hashbrown::HashMap;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub(crate) struct ProverKey {
@@ -23,7 +27,131 @@ pub(crate) struct ProverKey {
      * domain elements] */
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for ProverKey {
+    fn zeroize(&mut self) {
+        let polys_evals = [
+            &mut self.left_sigma,
+            &mut self.right_sigma,
+            &mut self.out_sigma,
+            &mut self.fourth_sigma,
+        ];
+        for (poly, evals) in polys_evals {
+            poly.zeroize();
+            evals.zeroize();
+        }
+        self.linear_evaluations.zeroize();
+    }
+}
+
 impl ProverKey {
+    /// Evaluates the four sigma polynomials over `domain`, returning for
+    /// each of the `4n` wire positions the index of the position it maps
+    /// to.
+    ///
+    /// The position of row `j` of the left, right, output and fourth wires
+    /// is `j`, `n + j`, `2n + j` and `3n + j` respectively, matching the
+    /// values `\omega^j`, `k_1 \omega^j`, `k_2 \omega^j` and `k_3 \omega^j`
+    /// of the sigmas.
+    ///
+    /// Returns [`Error::InvalidPermutation`] if a sigma does not fit in the
+    /// domain or evaluates to a value that is not a wire position.
+    pub(crate) fn permutation_map(
+        &self,
+        domain: &EvaluationDomain,
+    ) -> Result<Vec<usize>, Error> {
+        let sigmas = [
+            &self.left_sigma.0,
+            &self.right_sigma.0,
+            &self.out_sigma.0,
+            &self.fourth_sigma.0,
+        ];
+        if sigmas.iter().any(|sigma| sigma.degree() >= domain.size()) {
+            return Err(Error::InvalidPermutation);
+        }
+
+        let cosets = [BlsScalar::one(), K1, K2, K3];
+        let positions: HashMap<[u8; 32], usize> = cosets
+            .iter()
+            .flat_map(|k| domain.elements().map(move |root| k * root))
+            .enumerate()
+            .map(|(index, position)| (position.to_bytes(), index))
+            .collect();
+
+        sigmas
+            .iter()
+            .flat_map(|sigma| domain.fft(sigma))
+            .map(|eval| {
+                positions
+                    .get(&eval.to_bytes())
+                    .copied()
+                    .ok_or(Error::InvalidPermutation)
+            })
+            .collect()
+    }
+
+    /// Checks that the four sigma polynomials, evaluated over `domain`, map
+    /// the `4n` wire positions `k_i * \omega^j` onto themselves, so that the
+    /// copy constraints they encode are sound.
+    ///
+    /// Returns [`Error::InvalidPermutation`] if a sigma does not fit in the
+    /// domain or if their image is not a permutation of the wire positions.
+    pub(crate) fn validate_permutation(
+        &self,
+        domain: &EvaluationDomain,
+    ) -> Result<(), Error> {
+        let map = self.permutation_map(domain)?;
+
+        // Every position has an image, so the map is a bijection as soon as
+        // no position is hit twice
+        let mut hit = vec![false; map.len()];
+        for index in map {
+            if core::mem::replace(&mut hit[index], true) {
+                return Err(Error::InvalidPermutation);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the four sigma polynomials restricted to the first
+    /// `new_domain.size()` rows of `domain`, with every wire position
+    /// `k_i * \omega^j` remapped to the roots of `new_domain`.
+    ///
+    /// Returns [`Error::TruncatedSupport`] if a copy constraint links one of
+    /// these rows to a row beyond them.
+    pub(crate) fn truncated_sigmas(
+        &self,
+        domain: &EvaluationDomain,
+        new_domain: &EvaluationDomain,
+    ) -> Result<[Polynomial; 4], Error> {
+        let (n, new_n) = (domain.size(), new_domain.size());
+        let map = self.permutation_map(domain)?;
+
+        let cosets = [BlsScalar::one(), K1, K2, K3];
+        let roots: Vec<BlsScalar> = new_domain.elements().collect();
+
+        // Positions are a bijection, so no row beyond `new_n` maps into the
+        // first rows once none of them maps beyond
+        let sigma = |wire: usize, name| -> Result<Polynomial, Error> {
+            let evals = map[wire * n..wire * n + new_n]
+                .iter()
+                .map(|&index| match index % n {
+                    row if row < new_n => Ok(cosets[index / n] * roots[row]),
+                    _ => Err(Error::TruncatedSupport { name, new_n }),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Polynomial::from_coefficients_vec(new_domain.ifft(&evals)))
+        };
+
+        Ok([
+            sigma(0, "left_sigma")?,
+            sigma(1, "right_sigma")?,
+            sigma(2, "out_sigma")?,
+            sigma(3, "fourth_sigma")?,
+        ])
+    }
+
     pub(crate) fn compute_quotient_i(
         &self,
         index: usize,
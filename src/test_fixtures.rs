@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Fixtures to prove and verify a tiny circuit end to end, and to
+//! preprocess circuits into keys.
+//!
+//! The module is only compiled with the `test-setup` feature. Its keys
+//! are meant for tests and must never be used in production.
+
+use crate::commitment_scheme::kzg10::{
+    CommitKey, OpeningKey, PublicParameters,
+};
+#[cfg(test)]
+use crate::constraint_system::helper::dummy_gadget;
+use crate::constraint_system::StandardComposer;
+use crate::error::Error;
+use crate::proof_system::{Proof, Prover, ProverKey, Verifier};
+use alloc::vec::Vec;
+use dusk_bls12_381::BlsScalar;
+use merlin::Transcript;
+use rand_core::OsRng;
+
+/// Preprocesses the circuit of `composer` with the seeded commit key of
+/// [`CommitKey::setup_for_tests`], returning the key along with the
+/// [`ProverKey`].
+///
+/// The commit key can preprocess circuits padded to up to `512` gates.
+pub fn preprocessed_key(
+    composer: &mut StandardComposer,
+) -> (CommitKey, ProverKey) {
+    let (commit_key, _) = CommitKey::setup_for_tests(1 << 9, 0).unwrap();
+    let prover_key = composer
+        .preprocess_prover(&commit_key, &mut Transcript::new(b"test"))
+        .unwrap();
+
+    (commit_key, prover_key)
+}
+
+/// Same as [`preprocessed_key`], for a circuit of `gates` dummy arithmetic
+/// gates added by `dummy_gadget`.
+#[cfg(test)]
+pub(crate) fn preprocessed_dummy_key(gates: usize) -> (CommitKey, ProverKey) {
+    let mut composer = StandardComposer::new();
+    dummy_gadget(gates, &mut composer);
+    preprocessed_key(&mut composer)
+}
+
+/// Witness of the circuit `a * b + c = d`, where `d` is a public input.
+#[derive(Debug, Clone, Copy)]
+pub struct TinyCircuit {
+    /// Left factor of the product.
+    pub a: BlsScalar,
+    /// Right factor of the product.
+    pub b: BlsScalar,
+    /// Constant added to the product.
+    pub c: BlsScalar,
+    /// Public result `a * b + c`.
+    pub d: BlsScalar,
+}
+
+impl TinyCircuit {
+    /// Builds a satisfied witness for `a * b + c`.
+    pub fn new(a: u64, b: u64, c: u64) -> Self {
+        let (a, b, c) = (BlsScalar::from(a), BlsScalar::from(b), c.into());
+
+        TinyCircuit {
+            a,
+            b,
+            c,
+            d: a * b + c,
+        }
+    }
+
+    /// Adds the gate of the circuit to `composer`.
+    ///
+    /// The circuit is padded with dummy constraints to a domain of `8`.
+    /// Over a domain of `4` the last chunk of the quotient is empty, and
+    /// the commit key refuses to commit to it.
+    pub fn gadget(&self, composer: &mut StandardComposer) {
+        let a = composer.add_input(self.a);
+        let b = composer.add_input(self.b);
+        let c = composer.add_input(self.c);
+
+        // a * b + c - d = 0
+        composer.poly_gate(
+            a,
+            b,
+            c,
+            BlsScalar::one(),
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+            BlsScalar::one(),
+            BlsScalar::zero(),
+            Some(-self.d),
+        );
+
+        composer.add_dummy_constraints();
+        composer.add_dummy_constraints();
+    }
+}
+
+impl Default for TinyCircuit {
+    fn default() -> Self {
+        TinyCircuit::new(0, 0, 0)
+    }
+}
+
+/// A [`Prover`] and a [`Verifier`] preprocessed for [`TinyCircuit`], along
+/// with the keys of the setup.
+pub struct TinyFixture {
+    /// Commit key of the setup, trimmed to the circuit.
+    pub commit_key: CommitKey,
+    /// Opening key matching `commit_key`.
+    pub opening_key: OpeningKey,
+    /// Prover preprocessed with `commit_key`.
+    pub prover: Prover,
+    /// Verifier preprocessed with `commit_key`.
+    pub verifier: Verifier,
+}
+
+impl TinyFixture {
+    /// Runs a fresh setup and preprocesses the circuit on both sides.
+    pub fn new() -> Result<Self, Error> {
+        let public_parameters = PublicParameters::setup(1 << 5, &mut OsRng)?;
+
+        let mut prover = Prover::new(b"tiny");
+        TinyCircuit::default().gadget(prover.mut_cs());
+        let size = prover.circuit_size().next_power_of_two();
+        let (commit_key, opening_key) = public_parameters.trim(2 * size)?;
+        prover.preprocess(&commit_key)?;
+        prover.clear_witness();
+
+        let mut verifier = Verifier::new(b"tiny");
+        TinyCircuit::default().gadget(verifier.mut_cs());
+        verifier.preprocess(&commit_key)?;
+
+        Ok(TinyFixture {
+            commit_key,
+            opening_key,
+            prover,
+            verifier,
+        })
+    }
+
+    /// Proves `circuit`, returning the proof with its dense public inputs.
+    pub fn prove(
+        &mut self,
+        circuit: &TinyCircuit,
+    ) -> Result<(Proof, Vec<BlsScalar>), Error> {
+        circuit.gadget(self.prover.mut_cs());
+        let public_inputs = self.prover.cs.construct_dense_pi_vec();
+        let proof = self.prover.prove(&self.commit_key)?;
+
+        Ok((proof, public_inputs))
+    }
+
+    /// Verifies `proof` against the dense `public_inputs`.
+    pub fn verify(
+        &self,
+        proof: &Proof,
+        public_inputs: &[BlsScalar],
+    ) -> Result<(), Error> {
+        self.verifier
+            .verify(proof, &self.opening_key, public_inputs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tiny_circuit_end_to_end() -> Result<(), Error> {
+        let mut fixture = TinyFixture::new()?;
+
+        // The same keys prove several witnesses
+        for (a, b, c) in [(3, 4, 5), (0, 7, 1), (u64::MAX, 2, 9)] {
+            let (proof, public_inputs) =
+                fixture.prove(&TinyCircuit::new(a, b, c))?;
+            fixture.verify(&proof, &public_inputs)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tiny_circuit_tampered_proof() -> Result<(), Error> {
+        let mut fixture = TinyFixture::new()?;
+        let (proof, public_inputs) =
+            fixture.prove(&TinyCircuit::new(3, 4, 5))?;
+        fixture.verify(&proof, &public_inputs)?;
+
+        let mut tampered = proof.clone();
+        tampered.evaluations.a_eval += BlsScalar::one();
+        assert!(fixture.verify(&tampered, &public_inputs).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.t_1_comm = tampered.t_2_comm;
+        assert!(fixture.verify(&tampered, &public_inputs).is_err());
+
+        let mut tampered_inputs = public_inputs;
+        tampered_inputs
+            .iter_mut()
+            .for_each(|pi| *pi += BlsScalar::one());
+        assert!(fixture.verify(&proof, &tampered_inputs).is_err());
+
+        Ok(())
+    }
+}
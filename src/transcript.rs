@@ -25,6 +25,16 @@ pub(crate) trait TranscriptProtocol {
 
     /// Append domain separator for the circuit size.
     fn circuit_domain_sep(&mut self, n: u64);
+
+    /// Append external context `ctx`, such as a session identifier or a
+    /// block height, with the given `label`.
+    ///
+    /// The context is preceded by the `b"dom-sep"`/`b"context"` separator and
+    /// by its length as a `u64`, labeled `b"ctx_len"`, so different splits
+    /// of the same bytes between `label` and `ctx` never collide. It is meant
+    /// to be appended before the verifier key is seeded with
+    /// `seed_transcript`, that is before preprocessing.
+    fn append_context(&mut self, label: &'static [u8], ctx: &[u8]);
 }
 
 impl TranscriptProtocol for Transcript {
@@ -47,4 +57,38 @@ impl TranscriptProtocol for Transcript {
         self.append_message(b"dom-sep", b"circuit_size");
         self.append_u64(b"n", n);
     }
+
+    fn append_context(&mut self, label: &'static [u8], ctx: &[u8]) {
+        self.append_message(b"dom-sep", b"context");
+        self.append_u64(b"ctx_len", ctx.len() as u64);
+        self.append_message(label, ctx);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn context_is_length_prefixed() {
+        let mut transcript = Transcript::new(b"ctx");
+        transcript.append_context(b"session", b"42");
+
+        let mut expected = Transcript::new(b"ctx");
+        expected.append_message(b"dom-sep", b"context");
+        expected.append_message(b"ctx_len", &2u64.to_le_bytes());
+        expected.append_message(b"session", b"42");
+
+        assert_eq!(
+            transcript.challenge_scalar(b"c"),
+            expected.challenge_scalar(b"c")
+        );
+
+        // Splitting the same bytes differently changes the state
+        let mut ab_c = Transcript::new(b"ctx");
+        ab_c.append_context(b"ab", b"c");
+        let mut a_bc = Transcript::new(b"ctx");
+        a_bc.append_context(b"a", b"bc");
+        assert_ne!(ab_c.challenge_scalar(b"c"), a_bc.challenge_scalar(b"c"));
+    }
 }
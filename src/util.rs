@@ -23,6 +23,19 @@ pub(crate) fn powers_of(
     powers
 }
 
+/// Overwrites every scalar with zero, using volatile writes so the compiler
+/// cannot elide them, and then clears the vector.
+#[cfg(feature = "zeroize")]
+pub(crate) fn zeroize_scalars(scalars: &mut Vec<BlsScalar>) {
+    use core::sync::atomic::{compiler_fence, Ordering};
+
+    scalars.iter_mut().for_each(|scalar| unsafe {
+        core::ptr::write_volatile(scalar, BlsScalar::zero())
+    });
+    compiler_fence(Ordering::SeqCst);
+    scalars.clear();
+}
+
 /// Generates a random BlsScalar using a RNG seed.
 pub(crate) fn random_scalar<R: RngCore + CryptoRng>(rng: &mut R) -> BlsScalar {
     BlsScalar::random(rng)
@@ -0,0 +1,25 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381::BlsScalar;
+use dusk_plonk::error::Error;
+use dusk_plonk::test_fixtures::{TinyCircuit, TinyFixture};
+
+#[test]
+fn tiny_fixture_prove_and_verify() -> Result<(), Error> {
+    let mut fixture = TinyFixture::new()?;
+
+    let (proof, public_inputs) = fixture.prove(&TinyCircuit::new(3, 4, 5))?;
+    fixture.verify(&proof, &public_inputs)?;
+
+    let mut tampered_inputs = public_inputs;
+    tampered_inputs
+        .iter_mut()
+        .for_each(|pi| *pi += BlsScalar::one());
+    assert!(fixture.verify(&proof, &tampered_inputs).is_err());
+
+    Ok(())
+}